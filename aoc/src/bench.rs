@@ -0,0 +1,296 @@
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use common::Solution;
+
+use crate::{days, error::Error};
+
+/// Repetitions of a single measurement, however fast it is.
+const MAX_RUNS: usize = 1000;
+/// Slowdowns smaller than this are timer noise, not regressions.
+const NOISE: Duration = Duration::from_micros(5);
+
+/// What is timed: reading the input or solving one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part(part) => f.pad(&format!("part{part}")),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Stage::Part)
+                .ok_or(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+/// Timings by day and stage, stored as CSV:
+///
+/// ```text
+/// day,stage,runs,median_ns,min_ns
+/// 1,parse,1000,41250,40100
+/// 1,part1,1000,18920,18700
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Results(BTreeMap<(u8, Stage), Timing>);
+
+impl Results {
+    const HEADER: &'static str = "day,stage,runs,median_ns,min_ns";
+
+    /// A missing file means that nothing was measured yet.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|line| Error::Baseline(path.into(), line)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Input(path.into(), err)),
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| Error::Output(path.into(), err))?;
+        }
+        fs::write(path, self.to_string()).map_err(|err| Error::Output(path.into(), err))
+    }
+
+    /// Fails with the offending line.
+    fn parse(text: &str) -> Result<Self, String> {
+        let mut results = Results::default();
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line == Self::HEADER {
+                continue;
+            }
+            let (key, timing) = Self::parse_row(line).ok_or_else(|| line.to_string())?;
+            results.0.insert(key, timing);
+        }
+        Ok(results)
+    }
+
+    fn parse_row(line: &str) -> Option<((u8, Stage), Timing)> {
+        let mut fields = line.split(',').map(str::trim);
+        let key = (fields.next()?.parse().ok()?, fields.next()?.parse().ok()?);
+        let runs = fields.next()?.parse().ok()?;
+        let median = Duration::from_nanos(fields.next()?.parse().ok()?);
+        let min = Duration::from_nanos(fields.next()?.parse().ok()?);
+        let timing = Timing { runs, median, min };
+        fields.next().is_none().then_some((key, timing))
+    }
+
+    /// Takes over every timing of `other`.
+    pub fn update(&mut self, other: Results) {
+        self.0.extend(other.0);
+    }
+}
+
+impl fmt::Display for Results {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", Self::HEADER)?;
+        for ((day, stage), timing) in &self.0 {
+            writeln!(
+                f,
+                "{day},{stage},{},{},{}",
+                timing.runs,
+                timing.median.as_nanos(),
+                timing.min.as_nanos()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Times parsing `input` and solving `parts` of it, each repeated until
+/// `budget` is used up. Every measurement runs at least once, so slow parts
+/// take as long as they take.
+pub fn measure<S: Solution>(
+    parts: &[u8],
+    input: &str,
+    budget: Duration,
+) -> Result<Vec<(Stage, Timing)>, Error> {
+    let input = common::normalize(input);
+    let parsed = S::parse(&input).map_err(|err| Error::Parse(err.on_day(S::DAY)))?;
+    let params = S::Params::default();
+
+    let mut timings = vec![(Stage::Parse, time(budget, || S::parse(&input)))];
+    for &part in parts {
+        let timing = match part {
+            1 => time(budget, || S::part1(&parsed, &params)),
+            _ => time(budget, || S::part2(&parsed, &params)),
+        };
+        timings.push((Stage::Part(part), timing));
+    }
+    Ok(timings)
+}
+
+fn time<T>(budget: Duration, mut f: impl FnMut() -> T) -> Timing {
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty() || (samples.len() < MAX_RUNS && start.elapsed() < budget) {
+        let run = Instant::now();
+        std::hint::black_box(f());
+        samples.push(run.elapsed());
+    }
+    samples.sort();
+    Timing {
+        runs: samples.len(),
+        median: samples[samples.len() / 2],
+        min: samples[0],
+    }
+}
+
+pub struct Options {
+    pub budget: Duration,
+    /// Slowdown of the median in percent that counts as a regression.
+    pub threshold: f64,
+    pub baseline: PathBuf,
+    pub output: PathBuf,
+    /// Store the results in the baseline instead of comparing with it.
+    pub save: bool,
+}
+
+/// Benchmarks `days` on their real input, all parts unless `part` is given,
+/// and writes the results to `options.output`. Returns whether no stage got
+/// slower than the baseline allows.
+pub fn bench(days: &[u8], part: Option<u8>, options: &Options) -> Result<bool, Error> {
+    let mut baseline = Results::read(&options.baseline)?;
+    let mut results = Results::default();
+    let mut regressions = 0;
+
+    for &day in days {
+        let parts = match part {
+            Some(part) if part == 0 || part > days::parts(day)? => {
+                return Err(Error::UnknownPart(day, part))
+            }
+            Some(part) => vec![part],
+            None => (1..=days::parts(day)?).collect(),
+        };
+        let path = crate::default_input(day);
+        let input = fs::read_to_string(&path).map_err(|err| Error::Input(path, err))?;
+
+        for (stage, timing) in days::bench(day, &parts, &input, options.budget)? {
+            let comparison = match baseline.0.get(&(day, stage)) {
+                _ if options.save => String::new(),
+                None => "new".to_string(),
+                Some(old) => {
+                    let change = percent_change(old.median, timing.median);
+                    if is_regression(old.median, timing.median, options.threshold) {
+                        regressions += 1;
+                        format!("REGRESSION {change:+.0}% (was {:.1?})", old.median)
+                    } else {
+                        format!("{change:+.0}%")
+                    }
+                }
+            };
+            println!(
+                "day {day:02} {stage:<6} {:>10.1?}  {:>4} runs  {comparison}",
+                timing.median, timing.runs
+            );
+            results.0.insert((day, stage), timing);
+        }
+    }
+
+    results.write(&options.output)?;
+    if options.save {
+        baseline.update(results);
+        baseline.write(&options.baseline)?;
+        println!("saved baseline {}", options.baseline.display());
+    } else if baseline.0.is_empty() {
+        println!(
+            "no baseline in {}, run with --save to create one",
+            options.baseline.display()
+        );
+    } else {
+        println!("{regressions} regressions above {}%", options.threshold);
+    }
+    Ok(regressions == 0)
+}
+
+fn percent_change(old: Duration, new: Duration) -> f64 {
+    (new.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0
+}
+
+fn is_regression(old: Duration, new: Duration, threshold: f64) -> bool {
+    new > old + NOISE && percent_change(old, new) > threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stages() {
+        assert_eq!("parse".parse(), Ok(Stage::Parse));
+        assert_eq!("part2".parse(), Ok(Stage::Part(2)));
+        assert_eq!(Stage::Part(1).to_string(), "part1");
+        assert!("solve".parse::<Stage>().is_err());
+    }
+
+    #[test]
+    fn results_round_trip() {
+        let text =
+            "day,stage,runs,median_ns,min_ns\n1,parse,1000,41250,40100\n1,part1,3,18920,18700\n";
+        let results = Results::parse(text).unwrap();
+        assert_eq!(
+            results.0[&(1, Stage::Part(1))],
+            Timing {
+                runs: 3,
+                median: Duration::from_nanos(18920),
+                min: Duration::from_nanos(18700),
+            }
+        );
+        assert_eq!(results.to_string(), text);
+        assert_eq!(
+            Results::parse("1,parse,1000"),
+            Err("1,parse,1000".to_string())
+        );
+    }
+
+    #[test]
+    fn regressions() {
+        let ms = Duration::from_millis;
+        assert!(is_regression(ms(100), ms(120), 10.0));
+        assert!(!is_regression(ms(100), ms(105), 10.0));
+        assert!(!is_regression(ms(100), ms(50), 10.0));
+        // Doubling a few microseconds is noise.
+        assert!(!is_regression(
+            Duration::from_micros(3),
+            Duration::from_micros(6),
+            10.0
+        ));
+    }
+
+    #[test]
+    fn time_runs_at_least_once() {
+        let timing = time(Duration::ZERO, || 1 + 1);
+        assert_eq!(timing.runs, 1);
+        assert_eq!(timing.median, timing.min);
+    }
+}
@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use common::{Answer, Solution};
+
+use crate::{
+    bench::{self, Stage, Timing},
+    error::Error,
+    params,
+};
+
+/// Registers the day crates with the runner, one `day => solution` per line.
+macro_rules! days {
+    ($($day:literal => $solution:ty,)*) => {
+        /// All days the runner knows about, in order.
+        pub const DAYS: &[u8] = &[$($day),*];
+
+        /// Solves `part` of `day` for the given puzzle input and renders the
+        /// answer.
+        pub fn run(day: u8, part: u8, input: &str, params: &[String]) -> Result<String, Error> {
+            match day {
+                $($day => solve::<$solution>(part, input, params),)*
+                _ => Err(Error::UnknownDay(day)),
+            }
+        }
+
+        /// Times parsing and solving `parts` of `day` on the given puzzle
+        /// input, see [`bench::measure`].
+        pub fn bench(
+            day: u8,
+            parts: &[u8],
+            input: &str,
+            budget: Duration,
+        ) -> Result<Vec<(Stage, Timing)>, Error> {
+            match day {
+                $($day => bench::measure::<$solution>(parts, input, budget),)*
+                _ => Err(Error::UnknownDay(day)),
+            }
+        }
+
+        /// The number of parts `day` has.
+        pub fn parts(day: u8) -> Result<u8, Error> {
+            match day {
+                $($day => Ok(part_count::<$solution>()),)*
+                _ => Err(Error::UnknownDay(day)),
+            }
+        }
+    };
+}
+
+days! {
+    1 => day_01::Day01,
+    2 => day_02::Day02,
+    3 => day_03::Day03,
+    4 => day_04::Day04,
+    5 => day_05::Day05,
+    6 => day_06::Day06,
+    7 => day_07::Day07,
+    8 => day_08::Day08,
+    9 => day_09::Day09,
+    10 => day_10::Day10,
+    11 => day_11::Day11,
+    12 => day_12::Day12,
+    13 => day_13::Day13,
+    14 => day_14::Day14,
+    15 => day_15::Day15,
+    16 => day_16::Day16,
+    17 => day_17::Day17,
+    18 => day_18::Day18,
+    19 => day_19::Day19,
+    20 => day_20::Day20,
+    21 => day_21::Day21,
+    22 => day_22::Day22,
+    23 => day_23::Day23,
+    24 => day_24::Day24,
+    25 => day_25::Day25,
+}
+
+fn part_count<S: Solution>() -> u8 {
+    if S::HAS_PART2 {
+        2
+    } else {
+        1
+    }
+}
+
+fn solve<S: Solution>(part: u8, input: &str, params: &[String]) -> Result<String, Error> {
+    let params: S::Params = params::parse(params)?;
+    if part == 0 || part > part_count::<S>() {
+        return Err(Error::UnknownPart(S::DAY, part));
+    }
+    let input =
+        S::parse(&common::normalize(input)).map_err(|err| Error::Parse(err.on_day(S::DAY)))?;
+    match part {
+        1 => S::part1(&input, &params).render(),
+        _ => S::part2(&input, &params).render(),
+    }
+    .map_err(|reason| Error::Unsolved(S::DAY, part, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_error_names_the_day() {
+        match run(2, 1, "Game 1: 3 blue\nGame 2: 4 rad", &[]) {
+            Err(Error::Parse(err)) => {
+                assert_eq!((err.day, err.line, err.column), (2, 2, 11));
+                assert_eq!(err.token, "rad");
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+
+    #[test]
+    fn saved_in_any_format() {
+        let example = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+        let expected = run(13, 1, example, &[]).unwrap();
+        assert_eq!(expected, "405");
+
+        let crlf = format!("\u{feff}{}\r\n\r\n", example.replace('\n', "\r\n"));
+        assert_eq!(run(13, 1, &crlf, &[]).unwrap(), expected);
+        let spaced = example.replace("\n\n", "\n  \n\n") + "\n";
+        assert_eq!(run(13, 1, &spaced, &[]).unwrap(), expected);
+    }
+
+    #[test]
+    fn unknown_part_before_parsing() {
+        assert!(matches!(
+            run(25, 2, "garbage", &[]),
+            Err(Error::UnknownPart(25, 2))
+        ));
+    }
+}
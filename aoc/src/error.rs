@@ -0,0 +1,62 @@
+use std::{fmt, io, path::PathBuf};
+
+use common::{ParamError, ParseError};
+
+#[derive(Debug)]
+pub enum Error {
+    UnknownDay(u8),
+    UnknownPart(u8, u8),
+    Input(PathBuf, io::Error),
+    /// A line of an answers file that is not `part<N>: <answer>`.
+    Answers(PathBuf, String),
+    /// A line of a benchmark results file that is not
+    /// `day,stage,runs,median_ns,min_ns`.
+    Baseline(PathBuf, String),
+    Output(PathBuf, io::Error),
+    /// The crate for a new day is already there.
+    Exists(PathBuf),
+    /// No list of days to add a new day to.
+    Register(PathBuf),
+    MalformedParam(String),
+    Param(ParamError),
+    Parse(ParseError),
+    /// A part found that the input has no answer.
+    Unsolved(u8, u8, String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownDay(day) => write!(f, "day {day} is not registered with the runner"),
+            Error::UnknownPart(day, part) => write!(f, "day {day} has no part {part}"),
+            Error::Input(path, err) => write!(f, "cannot read {}: {err}", path.display()),
+            Error::Answers(path, line) => {
+                write!(
+                    f,
+                    "{}: `{line}` is not of the form partN: answer",
+                    path.display()
+                )
+            }
+            Error::Baseline(path, line) => {
+                write!(
+                    f,
+                    "{}: `{line}` is not of the form day,stage,runs,median_ns,min_ns",
+                    path.display()
+                )
+            }
+            Error::Output(path, err) => write!(f, "cannot write {}: {err}", path.display()),
+            Error::Exists(path) => write!(f, "{} already exists", path.display()),
+            Error::Register(path) => {
+                write!(f, "cannot find the list of days in {}", path.display())
+            }
+            Error::MalformedParam(param) => {
+                write!(f, "parameter `{param}` is not of the form key=value")
+            }
+            Error::Param(err) => err.fmt(f),
+            Error::Parse(err) => err.fmt(f),
+            Error::Unsolved(day, part, reason) => write!(f, "day {day} part {part}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,243 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io::{self, IsTerminal},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Map, Value};
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Subscriber,
+};
+use tracing_subscriber::{
+    filter::LevelFilter,
+    fmt::{format::Writer, FmtContext, FormatEvent, FormatFields},
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    util::SubscriberInitExt,
+    Layer,
+};
+
+/// How the runner logs. Solvers only emit events and spans, installing the
+/// subscriber is up to the runner.
+pub struct Options {
+    pub level: LevelFilter,
+    /// One JSON object per line instead of text.
+    pub json: bool,
+    /// Sum up the time spent in each span and print it when done.
+    pub span_timings: bool,
+}
+
+/// Installs the global subscriber, logging to stderr so that stdout only
+/// carries answers. The span timings are printed when the returned guard is
+/// dropped.
+pub fn init(options: &Options) -> Guard {
+    let output = if options.json {
+        tracing_subscriber::fmt::layer()
+            .event_format(Json)
+            .with_writer(io::stderr)
+            .boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .without_time()
+            .with_target(false)
+            .with_ansi(io::stderr().is_terminal())
+            .with_writer(io::stderr)
+            .boxed()
+    };
+    let timings = options.span_timings.then(SpanTimings::default);
+
+    tracing_subscriber::registry()
+        .with(output.with_filter(options.level))
+        .with(timings.clone())
+        .init();
+    Guard { timings }
+}
+
+pub struct Guard {
+    timings: Option<SpanTimings>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if let Some(timings) = &self.timings {
+            eprint!("{timings}");
+        }
+    }
+}
+
+/// Busy time and number of runs per span name. Spans are timed whether or not
+/// their level is logged.
+#[derive(Clone, Default)]
+struct SpanTimings(Arc<Mutex<BTreeMap<&'static str, (u64, Duration)>>>);
+
+/// When the span was last entered, kept in its extensions.
+struct Entered(Instant);
+
+impl<S> Layer<S> for SpanTimings
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().replace(Entered(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(Entered(start)) = span.extensions_mut().remove::<Entered>() {
+                let mut totals = self.0.lock().unwrap();
+                totals.entry(span.name()).or_default().1 += start.elapsed();
+            }
+        }
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            self.0.lock().unwrap().entry(span.name()).or_default().0 += 1;
+        }
+    }
+}
+
+impl fmt::Display for SpanTimings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let totals = self.0.lock().unwrap();
+        let width = totals.keys().map(|name| name.len()).max().unwrap_or(0);
+        writeln!(
+            f,
+            "{:width$}  {:>8}  {:>10}  {:>10}",
+            "span", "runs", "total", "mean"
+        )?;
+        for (name, &(runs, total)) in totals.iter() {
+            let mean = total / runs.max(1) as u32;
+            writeln!(f, "{name:width$}  {runs:>8}  {total:>10.1?}  {mean:>10.1?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes each event as a JSON object with its level, target, the names of
+/// the spans it happened in and its fields.
+struct Json;
+
+impl<S, N> FormatEvent<S, N> for Json
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        let mut fields = JsonFields::default();
+        event.record(&mut fields);
+        let spans: Vec<&str> = ctx
+            .event_scope()
+            .into_iter()
+            .flat_map(|scope| scope.from_root())
+            .map(|span| span.name())
+            .collect();
+        let metadata = event.metadata();
+        let line = json!({
+            "level": metadata.level().as_str(),
+            "target": metadata.target(),
+            "spans": spans,
+            "fields": fields.0,
+        });
+        writeln!(writer, "{line}")
+    }
+}
+
+#[derive(Default)]
+struct JsonFields(Map<String, Value>);
+
+impl JsonFields {
+    fn insert(&mut self, field: &Field, value: impl Into<Value>) {
+        self.0.insert(field.name().to_string(), value.into());
+    }
+}
+
+impl Visit for JsonFields {
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, value);
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, value);
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, value);
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, value);
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{value:?}"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::{info, info_span};
+
+    /// Collects what the subscriber writes.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn json_lines() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry().with(
+            tracing_subscriber::fmt::layer()
+                .event_format(Json)
+                .with_writer(move || writer.clone()),
+        );
+        tracing::subscriber::with_default(subscriber, || {
+            info_span!("solve").in_scope(|| info!(presses = 4, "rx low"));
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line: Value = serde_json::from_str(output.trim()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["spans"], json!(["solve"]));
+        assert_eq!(line["fields"], json!({"message": "rx low", "presses": 4}));
+    }
+
+    #[test]
+    fn span_timings() {
+        let timings = SpanTimings::default();
+        let subscriber = tracing_subscriber::registry().with(timings.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            for _ in 0..3 {
+                info_span!("step").in_scope(|| {});
+            }
+        });
+
+        let totals = timings.0.lock().unwrap();
+        assert_eq!(totals["step"].0, 3);
+    }
+}
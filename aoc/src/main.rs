@@ -0,0 +1,185 @@
+mod bench;
+mod days;
+mod error;
+mod logging;
+mod new;
+mod params;
+mod progress;
+mod verify;
+
+use clap::{Parser, Subcommand};
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use tracing_subscriber::filter::LevelFilter;
+
+use crate::error::Error;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2023 runner")]
+struct Cli {
+    /// Most detailed log level shown on stderr: off, error, warn, info, debug
+    /// or trace
+    #[arg(long, global = true, env = "AOC_LOG", default_value_t = LevelFilter::WARN)]
+    log: LevelFilter,
+    /// Log one JSON object per line
+    #[arg(long, global = true)]
+    log_json: bool,
+    /// Print the time spent in each span when done
+    #[arg(long, global = true)]
+    span_timings: bool,
+    /// How `run` shows the progress of slow parts
+    #[arg(long, global = true, value_enum, default_value_t = progress::Mode::Auto)]
+    progress: progress::Mode,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one part of a day and print the answer
+    Run {
+        day: u8,
+        part: u8,
+        /// Puzzle input, defaults to `day-XX/res/input`
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Puzzle parameter such as `steps=64`, may be repeated
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<String>,
+    },
+    /// Solve every part on the real input and compare with the known answers
+    /// in `day-XX/res/input.answers`
+    Verify {
+        /// Only these days, all by default
+        days: Vec<u8>,
+        /// Seconds after which a part is given up on and fails
+        #[arg(long, default_value_t = 60)]
+        timeout: u64,
+    },
+    /// Time parsing and every part on the real input and compare with the
+    /// saved baseline
+    Bench {
+        /// Only this day, all by default
+        day: Option<u8>,
+        /// Only this part, all by default
+        part: Option<u8>,
+        /// Seconds to keep repeating each measurement, which runs at least
+        /// once
+        #[arg(long, default_value_t = 1.0)]
+        time: f64,
+        /// Slowdown in percent that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// Results to compare with, defaults to `bench/baseline.csv`
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Where to write the results, defaults to `bench/latest.csv`
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Store the results in the baseline instead of comparing
+        #[arg(long)]
+        save: bool,
+    },
+    /// Create the crate for a new day from `template/` and register it with
+    /// the workspace and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+pub fn default_input(day: u8) -> PathBuf {
+    [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        &format!("day-{day:02}"),
+        "res",
+        "input",
+    ]
+    .iter()
+    .collect()
+}
+
+fn bench_file(name: &str) -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "bench", name]
+        .iter()
+        .collect()
+}
+
+fn run(day: u8, part: u8, input: Option<PathBuf>, params: &[String]) -> Result<String, Error> {
+    let path = input.unwrap_or_else(|| default_input(day));
+    let input = read_to_string(&path).map_err(|err| Error::Input(path, err))?;
+    days::run(day, part, &input, params)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _logging = logging::init(&logging::Options {
+        level: cli.log,
+        json: cli.log_json,
+        span_timings: cli.span_timings,
+    });
+
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            params,
+        } => {
+            progress::init(cli.progress);
+            run(day, part, input, &params).map(|answer| {
+                println!("{answer}");
+                true
+            })
+        }
+        Command::Verify { days, timeout } => {
+            let days = if days.is_empty() { days::DAYS } else { &days };
+            verify::verify(days, Duration::from_secs(timeout))
+        }
+        Command::Bench {
+            day,
+            part,
+            time,
+            threshold,
+            baseline,
+            output,
+            save,
+        } => {
+            let days = match &day {
+                Some(day) => std::slice::from_ref(day),
+                None => days::DAYS,
+            };
+            let options = bench::Options {
+                budget: Duration::from_secs_f64(time),
+                threshold,
+                baseline: baseline.unwrap_or_else(|| bench_file("baseline.csv")),
+                output: output.unwrap_or_else(|| bench_file("latest.csv")),
+                save,
+            };
+            bench::bench(days, part, &options)
+        }
+        Command::New { day } => {
+            let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+            new::generate(&workspace, day).map(|path| {
+                println!("created {}", path.display());
+                println!("rebuild the runner to solve it with `aoc run {day} <part>`");
+                true
+            })
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::error::Error;
+
+/// Creates `day-XX` in `workspace` from `template/` and registers it with the
+/// workspace and the runner.
+///
+/// The template is a crate of its own, in which `day-xx`, `day_xx`, `DayXX`
+/// and `const DAY: u8 = 0;` stand for the new day.
+pub fn generate(workspace: &Path, day: u8) -> Result<PathBuf, Error> {
+    scaffold(&workspace.join("template"), workspace, day)
+}
+
+fn scaffold(template: &Path, workspace: &Path, day: u8) -> Result<PathBuf, Error> {
+    let name = format!("day-{day:02}");
+    let target = workspace.join(&name);
+    if target.exists() {
+        return Err(Error::Exists(target));
+    }
+    copy(template, &target, day)?;
+
+    edit(&workspace.join("Cargo.toml"), |text| {
+        register(text, &format!("    \"{name}\","), day, |line| {
+            line.trim()
+                .strip_prefix("\"day-")?
+                .strip_suffix("\",")?
+                .parse()
+                .ok()
+        })
+    })?;
+    edit(&workspace.join("aoc/Cargo.toml"), |text| {
+        register(
+            text,
+            &format!("{name} = {{ path = \"../{name}\" }}"),
+            day,
+            |line| line.strip_prefix("day-")?.split_once(' ')?.0.parse().ok(),
+        )
+    })?;
+    edit(&workspace.join("aoc/src/days.rs"), |text| {
+        register(
+            text,
+            &format!("    {day} => day_{day:02}::Day{day:02},"),
+            day,
+            |line| {
+                let (day, solution) = line.trim().split_once(" => day_")?;
+                solution.contains("::Day").then_some(day.parse().ok()?)
+            },
+        )
+    })?;
+    Ok(target)
+}
+
+/// Copies the template, filling in the day. Build output is left behind.
+fn copy(from: &Path, to: &Path, day: u8) -> Result<(), Error> {
+    fs::create_dir_all(to).map_err(|err| Error::Output(to.into(), err))?;
+    let entries = fs::read_dir(from).map_err(|err| Error::Input(from.into(), err))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| Error::Input(from.into(), err))?;
+        let path = entry.path();
+        let name = entry.file_name();
+        if name == "target" || name == "Cargo.lock" {
+            continue;
+        }
+        if path.is_dir() {
+            copy(&path, &to.join(&name), day)?;
+        } else {
+            let text = fs::read_to_string(&path).map_err(|err| Error::Input(path, err))?;
+            let path = to.join(&name);
+            fs::write(&path, fill_in(&text, day)).map_err(|err| Error::Output(path, err))?;
+        }
+    }
+    Ok(())
+}
+
+fn fill_in(text: &str, day: u8) -> String {
+    text.replace("day-xx", &format!("day-{day:02}"))
+        .replace("day_xx", &format!("day_{day:02}"))
+        .replace("DayXX", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {day};"))
+}
+
+fn edit(path: &Path, f: impl FnOnce(&str) -> Option<String>) -> Result<(), Error> {
+    let text = fs::read_to_string(path).map_err(|err| Error::Input(path.into(), err))?;
+    let text = f(&text).ok_or_else(|| Error::Register(path.into()))?;
+    fs::write(path, text).map_err(|err| Error::Output(path.into(), err))
+}
+
+/// Adds `entry` to the list of days in `text`, whose lines `day_of`
+/// recognizes, keeping it in order. `None` if there is no such list.
+fn register(
+    text: &str,
+    entry: &str,
+    day: u8,
+    day_of: impl Fn(&str) -> Option<u8>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+    if days.iter().any(|&(_, other)| other == day) {
+        return Some(text.to_string());
+    }
+    let at = match days.iter().rev().find(|&&(_, other)| other < day) {
+        Some(&(i, _)) => i + 1,
+        None => days.first()?.0,
+    };
+    lines.insert(at, entry);
+    Some(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_in_day() {
+        assert_eq!(
+            fill_in(
+                "use day_xx::DayXX;\nname = \"day-xx\"\nconst DAY: u8 = 0;",
+                7
+            ),
+            "use day_07::Day07;\nname = \"day-07\"\nconst DAY: u8 = 7;"
+        );
+    }
+
+    #[test]
+    fn register_in_order() {
+        let members = "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-03\",\n]\n";
+        let day_of = |line: &str| line.trim().strip_prefix("\"day-")?.get(..2)?.parse().ok();
+        assert_eq!(
+            register(members, "    \"day-02\",", 2, day_of).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-02\",\n    \"day-03\",\n]\n"
+        );
+        assert_eq!(
+            register(members, "    \"day-04\",", 4, day_of).unwrap(),
+            "members = [\n    \"aoc\",\n    \"day-01\",\n    \"day-03\",\n    \"day-04\",\n]\n"
+        );
+        assert_eq!(
+            register(members, "    \"day-03\",", 3, day_of).unwrap(),
+            members
+        );
+        assert_eq!(register("members = []\n", "", 1, day_of), None);
+    }
+
+    #[test]
+    fn generate_from_template() {
+        let real = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let workspace = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        fs::create_dir_all(workspace.join("aoc/src")).unwrap();
+        let text = |file: &str| fs::read_to_string(workspace.join(file)).unwrap();
+        // A workspace in which day 25 does not exist yet.
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let text = fs::read_to_string(real.join(file)).unwrap();
+            let kept: Vec<&str> = text
+                .lines()
+                .filter(|line| !line.contains("day-25") && !line.contains("day_25"))
+                .collect();
+            fs::write(workspace.join(file), kept.join("\n") + "\n").unwrap();
+        }
+
+        let created = scaffold(&real.join("template"), &workspace, 25).unwrap();
+        assert!(text("day-25/src/lib.rs").contains("const DAY: u8 = 25;"));
+        assert!(text("day-25/benches/benchmark.rs").contains("use day_25::Day25;"));
+        assert!(created.join("res/examples/example.answers").exists());
+        assert!(text("Cargo.toml").contains("    \"day-24\",\n    \"day-25\",\n]"));
+        assert!(text("aoc/Cargo.toml").contains("\" }\nday-25 = { path = \"../day-25\" }\n"));
+        assert!(text("aoc/src/days.rs").contains("    25 => day_25::Day25,\n}"));
+        assert!(matches!(
+            scaffold(&real.join("template"), &workspace, 25),
+            Err(Error::Exists(_))
+        ));
+
+        fs::remove_dir_all(workspace).unwrap();
+    }
+}
@@ -0,0 +1,77 @@
+use crate::error::Error;
+
+/// Builds the puzzle parameters from `--param key=value` arguments, starting
+/// from the defaults for the real input.
+pub fn parse<P: common::Params>(args: &[String]) -> Result<P, Error> {
+    let mut params = P::default();
+    for arg in args {
+        let (key, value) = arg
+            .split_once('=')
+            .ok_or_else(|| Error::MalformedParam(arg.clone()))?;
+        params.set(key.trim(), value.trim()).map_err(Error::Param)?;
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{parse_param, ParamError};
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Steps {
+        steps: usize,
+    }
+
+    impl common::Params for Steps {
+        fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+            match key {
+                "steps" => self.steps = parse_param(key, value)?,
+                _ => return Err(ParamError::Unknown(key.to_string())),
+            }
+            Ok(())
+        }
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_when_missing() {
+        assert_eq!(parse::<Steps>(&[]).unwrap(), Steps::default());
+    }
+
+    #[test]
+    fn parsed_value() {
+        assert_eq!(
+            parse::<Steps>(&args(&["steps = 6"])).unwrap(),
+            Steps { steps: 6 }
+        );
+    }
+
+    #[test]
+    fn invalid_value() {
+        assert!(matches!(
+            parse::<Steps>(&args(&["steps=six"])),
+            Err(Error::Param(ParamError::Invalid { .. }))
+        ));
+    }
+
+    #[test]
+    fn malformed() {
+        assert!(matches!(
+            parse::<Steps>(&args(&["steps"])),
+            Err(Error::MalformedParam(_))
+        ));
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(matches!(
+            parse::<Steps>(&args(&["stpes=6"])),
+            Err(Error::Param(ParamError::Unknown(_)))
+        ));
+        assert!(parse::<()>(&args(&["steps=6"])).is_err());
+    }
+}
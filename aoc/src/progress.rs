@@ -0,0 +1,162 @@
+use std::{
+    io::{self, IsTerminal},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use clap::ValueEnum;
+use common::{Reporter, Task};
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Steps between looking at the clock, so that logging stays cheap in tight
+/// loops.
+const CHECK_EVERY: u64 = 4096;
+/// Time between two log lines of the same task.
+const LOG_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How the progress of long-running solvers is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Mode {
+    /// A bar if stderr is a terminal, nothing otherwise
+    Auto,
+    /// A bar on stderr
+    Bar,
+    /// An info log line every few seconds, shown with `--log info`
+    Log,
+    /// Nothing
+    Off,
+}
+
+/// Installs the reporter for `mode`.
+pub fn init(mode: Mode) {
+    let reporter: Box<dyn Reporter> = match mode {
+        Mode::Auto if io::stderr().is_terminal() => Box::new(Bars),
+        Mode::Bar => Box::new(Bars),
+        Mode::Log => Box::new(Logs),
+        Mode::Auto | Mode::Off => return,
+    };
+    // Only the runner installs one, and only once.
+    let _ = common::set_reporter(reporter);
+}
+
+struct Bars;
+
+impl Reporter for Bars {
+    fn start(&self, name: &str, total: u64) -> Box<dyn Task> {
+        let style = ProgressStyle::default_bar()
+            .template("{spinner:.green} {msg} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+            .unwrap()
+            .progress_chars("#>-");
+        let bar = ProgressBar::new(total)
+            .with_style(style)
+            .with_message(name.to_string());
+        Box::new(Bar(bar))
+    }
+}
+
+struct Bar(ProgressBar);
+
+impl Task for Bar {
+    fn advance(&self, steps: u64) {
+        self.0.inc(steps);
+    }
+
+    fn finish(&self) {
+        self.0.finish();
+    }
+}
+
+struct Logs;
+
+impl Reporter for Logs {
+    fn start(&self, name: &str, total: u64) -> Box<dyn Task> {
+        Box::new(Log::new(name, total))
+    }
+}
+
+struct Log {
+    name: String,
+    total: u64,
+    done: AtomicU64,
+    start: Instant,
+    /// When the last line was logged.
+    last: Mutex<Instant>,
+}
+
+impl Log {
+    fn new(name: &str, total: u64) -> Self {
+        let now = Instant::now();
+        Log {
+            name: name.to_string(),
+            total,
+            done: AtomicU64::new(0),
+            start: now,
+            last: Mutex::new(now),
+        }
+    }
+
+    /// Whether advancing by `steps` to `done` crossed a multiple of
+    /// [`CHECK_EVERY`].
+    fn is_check(done: u64, steps: u64) -> bool {
+        done / CHECK_EVERY != (done - steps) / CHECK_EVERY
+    }
+}
+
+impl Task for Log {
+    fn advance(&self, steps: u64) {
+        let done = self.done.fetch_add(steps, Ordering::Relaxed) + steps;
+        if !Self::is_check(done, steps) {
+            return;
+        }
+        // Whoever holds the lock is about to log anyway.
+        let Ok(mut last) = self.last.try_lock() else {
+            return;
+        };
+        if last.elapsed() >= LOG_INTERVAL {
+            *last = Instant::now();
+            tracing::info!(
+                "{}: {done}/{} ({:.0?})",
+                self.name,
+                self.total,
+                self.start.elapsed()
+            );
+        }
+    }
+
+    fn finish(&self) {
+        tracing::info!(
+            "{}: done {}/{} in {:.1?}",
+            self.name,
+            self.done.load(Ordering::Relaxed),
+            self.total,
+            self.start.elapsed()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn checks_when_crossing() {
+        assert!(Log::is_check(CHECK_EVERY, 1));
+        assert!(!Log::is_check(CHECK_EVERY + 1, 1));
+        assert!(Log::is_check(CHECK_EVERY + 10, 20));
+    }
+
+    #[test]
+    fn log_counts_all_threads() {
+        let log = Log::new("pairs", 40_000);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| (0..10_000).for_each(|_| log.advance(1)));
+            }
+        });
+        assert_eq!(log.done.load(Ordering::Relaxed), 40_000);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{
+    env, fmt, fs, io,
+    io::Read,
+    path::Path,
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::Answers;
+
+use crate::{days, error::Error};
+
+/// The known answers next to `input`, see [`Answers`]. A missing file means
+/// that no answers are known.
+fn read_answers(input: &Path) -> Result<Answers, Error> {
+    let path = Answers::path(input);
+    match fs::read_to_string(&path) {
+        Ok(text) => Answers::parse(&text).map_err(|line| Error::Answers(path, line)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+        Err(err) => Err(Error::Input(path, err)),
+    }
+}
+
+enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        got: String,
+    },
+    /// No known answer, with whatever the solver came up with.
+    Missing(Option<String>),
+    Error(String),
+    Timeout,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, got } => write!(f, "FAIL     got {got}, expected {expected}"),
+            Outcome::Missing(Some(got)) => write!(f, "missing  got {got}"),
+            Outcome::Missing(None) => write!(f, "missing  no answer in time"),
+            Outcome::Error(err) => write!(f, "ERROR    {err}"),
+            Outcome::Timeout => write!(f, "TIMEOUT"),
+        }
+    }
+}
+
+/// Solves every part of `days` on its real input in a child process and
+/// compares the answers with the known ones. Returns whether every known
+/// answer was matched in time; a part that runs out of time fails the check
+/// like a wrong answer, slow days need a longer `timeout`.
+pub fn verify(days: &[u8], timeout: Duration) -> Result<bool, Error> {
+    let (mut passed, mut failed, mut missing, mut timed_out) = (0, 0, 0u8, 0);
+
+    for &day in days {
+        let parts = days::parts(day)?;
+        let input = crate::default_input(day);
+        if !input.exists() {
+            println!("day {day:02}  missing input {}", input.display());
+            missing += parts;
+            continue;
+        }
+        let answers = read_answers(&input)?;
+
+        for part in 1..=parts {
+            let start = Instant::now();
+            let result = solve(day, part, timeout);
+            let elapsed = start.elapsed();
+
+            let outcome = match (answers.get(part), result) {
+                (None, Ok(Some(got))) => Outcome::Missing(Some(got)),
+                (None, Ok(None)) => Outcome::Missing(None),
+                (_, Err(err)) => Outcome::Error(err),
+                (Some(_), Ok(None)) => Outcome::Timeout,
+                (Some(expected), Ok(Some(got))) if expected == got => Outcome::Pass,
+                (Some(expected), Ok(Some(got))) => Outcome::Fail {
+                    expected: expected.to_string(),
+                    got,
+                },
+            };
+            match outcome {
+                Outcome::Pass => passed += 1,
+                Outcome::Missing(_) => missing += 1,
+                Outcome::Timeout => timed_out += 1,
+                Outcome::Fail { .. } | Outcome::Error(_) => failed += 1,
+            }
+            println!("day {day:02} part {part}  {elapsed:>10.1?}  {outcome}");
+        }
+    }
+
+    println!(
+        "{passed} passed, {failed} failed, {timed_out} timed out, {missing} without a known answer"
+    );
+    Ok(failed == 0 && timed_out == 0)
+}
+
+/// Runs `aoc run <day> <part>` on the real input. `Ok(None)` if it did not
+/// finish within `timeout`.
+fn solve(day: u8, part: u8, timeout: Duration) -> Result<Option<String>, String> {
+    let exe = env::current_exe().map_err(|err| err.to_string())?;
+    let mut child = Command::new(exe)
+        .args(["run", &day.to_string(), &part.to_string()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| err.to_string())?;
+
+    // Drain both pipes on the side, solvers may log a lot.
+    let drain = |mut pipe: Box<dyn Read + Send>| {
+        thread::spawn(move || {
+            let mut out = String::new();
+            pipe.read_to_string(&mut out).ok();
+            out
+        })
+    };
+    let stdout = drain(Box::new(child.stdout.take().unwrap()));
+    let stderr = drain(Box::new(child.stderr.take().unwrap()));
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|err| err.to_string())? {
+            break status;
+        }
+        if start.elapsed() > timeout {
+            child.kill().ok();
+            child.wait().ok();
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(1));
+    };
+
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    if status.success() {
+        Ok(Some(stdout.trim().to_string()))
+    } else {
+        let last = stderr.lines().rev().find(|line| !line.trim().is_empty());
+        Err(last.unwrap_or("failed without output").trim().to_string())
+    }
+}
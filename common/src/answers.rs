@@ -0,0 +1,132 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{ParamError, Params};
+
+/// The known answers for one puzzle input, kept next to it in
+/// `<input>.answers`, one per line:
+///
+/// ```text
+/// part1: 16
+/// part2 steps=10: 50
+/// ```
+///
+/// Parameters given after the part override the defaults for the real input.
+/// Blank lines and lines starting with `#` are ignored, parts without a line
+/// have no known answer yet.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    cases: Vec<Case>,
+}
+
+/// One known answer: `part` solved with `params` gives `answer`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Case {
+    pub part: u8,
+    pub params: Vec<(String, String)>,
+    pub answer: String,
+}
+
+impl Answers {
+    pub fn path(input: &Path) -> PathBuf {
+        let mut path = input.as_os_str().to_owned();
+        path.push(".answers");
+        path.into()
+    }
+
+    /// Fails with the offending line.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let cases = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| Case::parse(line).ok_or_else(|| line.to_string()))
+            .collect::<Result<_, _>>()?;
+        Ok(Answers { cases })
+    }
+
+    pub fn cases(&self) -> &[Case] {
+        &self.cases
+    }
+
+    /// The answer for `part` with the default parameters.
+    pub fn get(&self, part: u8) -> Option<&str> {
+        self.cases
+            .iter()
+            .find(|case| case.part == part && case.params.is_empty())
+            .map(|case| case.answer.as_str())
+    }
+}
+
+impl Case {
+    fn parse(line: &str) -> Option<Self> {
+        let (head, answer) = line.split_once(':')?;
+        let mut words = head.split_whitespace();
+        let part = words.next()?.strip_prefix("part")?.parse().ok()?;
+        let params = words
+            .map(|param| {
+                let (key, value) = param.split_once('=')?;
+                Some((key.to_string(), value.to_string()))
+            })
+            .collect::<Option<_>>()?;
+        Some(Case {
+            part,
+            params,
+            answer: answer.trim().to_string(),
+        })
+    }
+
+    /// The defaults with this case's parameters applied.
+    pub fn params<P: Params>(&self) -> Result<P, ParamError> {
+        let mut params = P::default();
+        for (key, value) in &self.params {
+            params.set(key, value)?;
+        }
+        Ok(params)
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "part{}", self.part)?;
+        for (key, value) in &self.params {
+            write!(f, " {key}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse("# real input\npart1: 54697\n\npart2:54885\n").unwrap();
+        assert_eq!(answers.get(1), Some("54697"));
+        assert_eq!(answers.get(2), Some("54885"));
+        assert_eq!(Answers::parse("").unwrap().get(1), None);
+        assert_eq!(Answers::parse("1: 2"), Err("1: 2".to_string()));
+    }
+
+    #[test]
+    fn parse_params() {
+        let answers = Answers::parse("part2 steps=10: 50\npart2: 7").unwrap();
+        let case = &answers.cases()[0];
+        assert_eq!(case.params, vec![("steps".to_string(), "10".to_string())]);
+        assert_eq!(case.to_string(), "part2 steps=10");
+        assert_eq!(answers.get(2), Some("7"));
+        assert!(case.params::<()>().is_err());
+        assert!(Answers::parse("part1 steps: 2").is_err());
+    }
+
+    #[test]
+    fn answers_next_to_input() {
+        assert_eq!(
+            Answers::path(Path::new("day-01/res/input")),
+            PathBuf::from("day-01/res/input.answers")
+        );
+    }
+}
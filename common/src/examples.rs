@@ -0,0 +1,62 @@
+use std::{fs, path::Path};
+
+use crate::{normalize, Answer, Answers, Solution};
+
+/// Solves the example at `path` for every case in `<path>.answers` and panics
+/// on the first wrong answer. An example without answers is not checked yet.
+/// Used by the tests that [`examples!`] generates.
+///
+/// [`examples!`]: crate::examples!
+pub fn check_example<S: Solution>(path: &Path) {
+    let read = |path: &Path| {
+        fs::read_to_string(path).unwrap_or_else(|err| panic!("{}: {err}", path.display()))
+    };
+    let answers = Answers::parse(&read(&Answers::path(path)))
+        .unwrap_or_else(|line| panic!("{}: malformed answer `{line}`", path.display()));
+    if answers.cases().is_empty() {
+        // A freshly scaffolded day, nothing to check yet.
+        return;
+    }
+    let input = S::parse(&normalize(&read(path)))
+        .unwrap_or_else(|err| panic!("{}: {}", path.display(), err.on_day(S::DAY)));
+
+    for case in answers.cases() {
+        let params: S::Params = case
+            .params()
+            .unwrap_or_else(|err| panic!("{}: {case}: {err}", path.display()));
+        let answer = match case.part {
+            1 => S::part1(&input, &params).render(),
+            2 if S::HAS_PART2 => S::part2(&input, &params).render(),
+            part => panic!("{}: day {} has no part {part}", path.display(), S::DAY),
+        }
+        .unwrap_or_else(|err| panic!("{}: {case}: {err}", path.display()));
+        assert_eq!(answer, case.answer, "{}: {case}", path.display());
+    }
+}
+
+/// Generates a test case for every example in the day's `res/examples/`,
+/// checked against the `.answers` file next to it:
+///
+/// ```ignore
+/// common::examples!(Day12);
+/// ```
+///
+/// Needs `rstest` as a dev-dependency of the day crate.
+#[macro_export]
+macro_rules! examples {
+    ($solution:ident) => {
+        #[cfg(test)]
+        mod examples {
+            use ::std::path::PathBuf;
+
+            #[::rstest::rstest]
+            fn example(
+                #[files("res/examples/*")]
+                #[exclude("\\.answers$")]
+                path: PathBuf,
+            ) {
+                $crate::check_example::<super::$solution>(&path);
+            }
+        }
+    };
+}
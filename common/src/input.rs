@@ -0,0 +1,82 @@
+/// Brings the puzzle input into the one shape the parsers expect, no matter
+/// how the file was saved: no byte order mark, `\n` line endings, no trailing
+/// whitespace on any line and no trailing blank lines or final newline.
+///
+/// Leading blank lines are kept so that line numbers in errors still match
+/// the file.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+    lines.join("\n")
+}
+
+/// The blocks of `input` separated by one or more blank lines, each with the
+/// index of its first line.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = input;
+    let mut line = 0;
+    std::iter::from_fn(move || {
+        // Skip the blank lines in front of the block.
+        loop {
+            if rest.is_empty() {
+                return None;
+            }
+            let (first, tail) = rest.split_once('\n').unwrap_or((rest, ""));
+            if !first.trim().is_empty() {
+                break;
+            }
+            rest = tail;
+            line += 1;
+        }
+
+        let start = line;
+        let mut len = 0;
+        for first in rest.split_inclusive('\n') {
+            if first.trim().is_empty() {
+                break;
+            }
+            len += first.len();
+            line += 1;
+        }
+        let (block, tail) = rest.split_at(len);
+        rest = tail;
+        Some((start, block.trim_end_matches(['\n', '\r'])))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_endings() {
+        let expected = "#.#\n...\n\n.#.";
+        assert_eq!(normalize("#.#\n...\n\n.#.\n"), expected);
+        assert_eq!(normalize("#.#\r\n...\r\n\r\n.#.\r\n"), expected);
+        assert_eq!(normalize("\u{feff}#.#  \n...\t\n \n.#.\n\n\n"), expected);
+        assert_eq!(normalize(expected), expected);
+    }
+
+    #[test]
+    fn leading_blank_lines_are_kept() {
+        assert_eq!(normalize("\n1\n"), "\n1");
+        assert_eq!(normalize("\n \n"), "");
+    }
+
+    #[test]
+    fn split_blocks() {
+        let input = "a\nb\n\nc\n \n\n\nd\ne\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<_>>(),
+            vec![(0, "a\nb"), (3, "c"), (7, "d\ne")]
+        );
+        assert_eq!(
+            blocks("\r\na\r\n\r\nb").collect::<Vec<_>>(),
+            vec![(1, "a"), (3, "b")]
+        );
+        assert_eq!(blocks("").count(), 0);
+    }
+}
@@ -0,0 +1,15 @@
+mod answers;
+mod examples;
+mod input;
+mod params;
+mod parse;
+mod progress;
+mod solution;
+
+pub use answers::{Answers, Case};
+pub use examples::check_example;
+pub use input::{blocks, normalize};
+pub use params::{parse_param, ParamError, Params};
+pub use parse::{check_chars, parse_blocks, parse_lines, parse_number, split_once, ParseError};
+pub use progress::{set_reporter, Progress, ProgressIterator, Reporter, Task, WithProgress};
+pub use solution::{Answer, Solution};
@@ -0,0 +1,61 @@
+use std::{fmt, str::FromStr};
+
+/// Typed puzzle parameters, such as the number of steps on day 21.
+///
+/// The defaults are the values used for the real puzzle input, examples
+/// override them by key.
+pub trait Params: Default {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError>;
+}
+
+/// For days without parameters.
+impl Params for () {
+    fn set(&mut self, key: &str, _value: &str) -> Result<(), ParamError> {
+        Err(ParamError::Unknown(key.to_string()))
+    }
+}
+
+pub fn parse_param<T: FromStr>(key: &str, value: &str) -> Result<T, ParamError> {
+    value.parse().map_err(|_| ParamError::Invalid {
+        key: key.to_string(),
+        value: value.to_string(),
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParamError {
+    Unknown(String),
+    Invalid { key: String, value: String },
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamError::Unknown(key) => write!(f, "parameter `{key}` is not used by this puzzle"),
+            ParamError::Invalid { key, value } => {
+                write!(f, "invalid value `{value}` for parameter `{key}`")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_has_no_params() {
+        assert_eq!(
+            ().set("steps", "6"),
+            Err(ParamError::Unknown("steps".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_param::<usize>("steps", "6"), Ok(6));
+        assert!(parse_param::<usize>("steps", "six").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{fmt, str::FromStr};
+
+use crate::blocks;
+
+/// Why the puzzle input could not be read.
+///
+/// Lines and columns count from 1. A line of 0 means the problem is not tied
+/// to a single line, e.g. a map without a start tile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Filled in by the runner, 0 until then.
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    /// The offending part of the input, empty if something is missing.
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// `token` was found in `line` where `expected` should have been. The
+    /// column is taken from the position of `token` if it is a slice of
+    /// `line`, otherwise it points at the end of the line.
+    pub fn new(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let start = line.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= line.len())
+            .unwrap_or(line.len());
+        ParseError {
+            day: 0,
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Something the puzzle needs is not in the input at all.
+    pub fn missing(expected: impl Into<String>) -> Self {
+        ParseError {
+            day: 0,
+            line: 0,
+            column: 0,
+            token: String::new(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Moves the error down by `lines`, for text that was cut out of a larger
+    /// input.
+    pub fn offset(mut self, lines: usize) -> Self {
+        if self.line > 0 {
+            self.line += lines;
+        }
+        self
+    }
+
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if self.line > 0 {
+            write!(f, ", line {}, column {}", self.line, self.column)?;
+        }
+        write!(f, ": expected {}", self.expected)?;
+        if !self.token.is_empty() {
+            write!(f, ", found `{}`", self.token.escape_debug())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses every line of `input`, numbering the errors accordingly.
+pub fn parse_lines<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.offset(i)))
+        .collect()
+}
+
+/// Parses the [`blocks`] of `input`, numbering the errors accordingly.
+pub fn parse_blocks<'a, T>(
+    input: &'a str,
+    mut parse: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    blocks(input)
+        .map(|(offset, block)| parse(block).map_err(|err| err.offset(offset)))
+        .collect()
+}
+
+/// Parses `token`, a slice of `line`, as a number.
+pub fn parse_number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, token, "a number"))
+}
+
+/// Splits `text`, a slice of `line`, at the first `separator`.
+pub fn split_once<'a>(
+    line: &str,
+    text: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(line, text, format!("`{separator}`")))
+}
+
+/// Fails on the first character of `text`, a slice of `line`, that is not
+/// `valid`.
+pub fn check_chars(
+    line: &str,
+    text: &str,
+    valid: impl Fn(char) -> bool,
+    expected: &str,
+) -> Result<(), ParseError> {
+    match text.char_indices().find(|&(_, c)| !valid(c)) {
+        Some((i, c)) => Err(ParseError::new(line, &text[i..i + c.len_utf8()], expected)),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_of_token() {
+        let line = "Card 1: 41 4x | 83";
+        let err = parse_number::<u32>(line, &line[11..13]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 12));
+        assert_eq!(err.token, "4x");
+        assert_eq!(
+            err.on_day(4).to_string(),
+            "day 4, line 1, column 12: expected a number, found `4x`"
+        );
+    }
+
+    #[test]
+    fn line_numbers() {
+        let err = parse_lines("1\n2\nthree", |line| parse_number::<u8>(line, line)).unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+
+        let err = parse_blocks("1\n2\n\n3\n4x", |block| {
+            parse_lines(block, |line| parse_number::<u8>(line, line))
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (5, 1));
+    }
+
+    #[test]
+    fn chars() {
+        let line = "???.### 1,1,3";
+        assert!(check_chars(line, &line[..7], |c| ".#?".contains(c), "a spring").is_ok());
+        let err = check_chars(line, line, |c| ".#?".contains(c), "a spring").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (8, " "));
+    }
+
+    #[test]
+    fn missing() {
+        assert_eq!(
+            split_once("a b", "a b", ": ").unwrap_err().to_string(),
+            "day 0, line 1, column 1: expected `: `, found `a b`"
+        );
+        assert_eq!(
+            ParseError::missing("a start tile `S`")
+                .offset(3)
+                .to_string(),
+            "day 0: expected a start tile `S`"
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::OnceLock;
+
+/// Shows how far along the long-running solvers are. The runner installs one
+/// with [`set_reporter`]; without one, as in tests and benchmarks, progress
+/// goes nowhere.
+pub trait Reporter: Send + Sync {
+    /// Starts a task of `total` steps.
+    fn start(&self, name: &str, total: u64) -> Box<dyn Task>;
+}
+
+/// A task started by a [`Reporter`], advanced from any number of threads.
+pub trait Task: Send + Sync {
+    fn advance(&self, steps: u64);
+    /// Called once, when the solver is done with the task.
+    fn finish(&self);
+}
+
+static REPORTER: OnceLock<Box<dyn Reporter>> = OnceLock::new();
+
+/// Fails with `reporter` if one was installed already.
+pub fn set_reporter(reporter: Box<dyn Reporter>) -> Result<(), Box<dyn Reporter>> {
+    REPORTER.set(reporter)
+}
+
+/// The progress of one task of a solver, finished when dropped. Can be shared
+/// between rayon workers:
+///
+/// ```
+/// use common::Progress;
+///
+/// let progress = Progress::new("bricks", 3);
+/// let fallen: usize = (0..3).map(|id| { progress.inc(); id }).sum();
+/// ```
+pub struct Progress {
+    task: Option<Box<dyn Task>>,
+}
+
+impl Progress {
+    pub fn new(name: &str, total: u64) -> Self {
+        Progress {
+            task: REPORTER.get().map(|reporter| reporter.start(name, total)),
+        }
+    }
+
+    pub fn inc(&self) {
+        if let Some(task) = &self.task {
+            task.advance(1);
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if let Some(task) = &self.task {
+            task.finish();
+        }
+    }
+}
+
+/// Advances a [`Progress`] for every item taken from an iterator.
+pub trait ProgressIterator: Iterator + Sized {
+    fn progress(self, progress: Progress) -> WithProgress<Self> {
+        WithProgress {
+            iter: self,
+            progress,
+        }
+    }
+}
+
+impl<I: Iterator> ProgressIterator for I {}
+
+pub struct WithProgress<I> {
+    iter: I,
+    progress: Progress,
+}
+
+impl<I: Iterator> Iterator for WithProgress<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        self.progress.inc();
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+        thread,
+    };
+
+    /// Remembers the steps of every task when it finishes.
+    #[derive(Clone, Default)]
+    struct Counter(Arc<Mutex<Vec<(String, u64, u64)>>>);
+
+    struct Counted {
+        name: String,
+        total: u64,
+        steps: AtomicU64,
+        finished: Counter,
+    }
+
+    impl Reporter for Counter {
+        fn start(&self, name: &str, total: u64) -> Box<dyn Task> {
+            Box::new(Counted {
+                name: name.to_string(),
+                total,
+                steps: AtomicU64::new(0),
+                finished: self.clone(),
+            })
+        }
+    }
+
+    impl Task for Counted {
+        fn advance(&self, steps: u64) {
+            self.steps.fetch_add(steps, Ordering::Relaxed);
+        }
+
+        fn finish(&self) {
+            let steps = self.steps.load(Ordering::Relaxed);
+            let mut finished = self.finished.0.lock().unwrap();
+            finished.push((self.name.clone(), self.total, steps));
+        }
+    }
+
+    #[test]
+    fn shared_between_threads() {
+        let counter = Counter::default();
+        assert!(set_reporter(Box::new(counter.clone())).is_ok());
+        assert!(set_reporter(Box::new(Counter::default())).is_err());
+
+        let progress = Progress::new("pairs", 4000);
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| (0..1000).for_each(|_| progress.inc()));
+            }
+        });
+        drop(progress);
+        assert_eq!((0..10).progress(Progress::new("lines", 10)).count(), 10);
+
+        assert_eq!(
+            *counter.0.lock().unwrap(),
+            vec![
+                ("pairs".to_string(), 4000, 4000),
+                ("lines".to_string(), 10, 10)
+            ]
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use std::{convert::Infallible, fmt::Display};
+
+use crate::{Params, ParseError};
+
+/// One day of the calendar.
+///
+/// The puzzle input is parsed once into [`Solution::Input`] and then handed to
+/// both parts, together with the puzzle parameters. The runner hands
+/// [`normalize`](crate::normalize)d input to [`Solution::parse`].
+pub trait Solution {
+    const DAY: u8;
+    /// Day 25 traditionally only has a single part.
+    const HAS_PART2: bool = true;
+
+    type Input;
+    type Params: Params;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Self::Params) -> Self::Answer1;
+    fn part2(input: &Self::Input, params: &Self::Params) -> Self::Answer2;
+}
+
+/// What a part returns: the answer to show, or for a `Result`, why the input
+/// has none.
+pub trait Answer {
+    fn render(&self) -> Result<String, String>;
+}
+
+macro_rules! shown_as_is {
+    ($($answer:ty),*) => {
+        $(impl Answer for $answer {
+            fn render(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+shown_as_is!(u16, u32, u64, u128, usize, i16, i32, i64, i128, isize, String);
+
+impl Answer for Infallible {
+    fn render(&self) -> Result<String, String> {
+        match *self {}
+    }
+}
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn render(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => answer.render(),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+extern crate day_01;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_01::Day01;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day01::parse(&normalize(include_str!("../res/examples/example_2"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day01::part2(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
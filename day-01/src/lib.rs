@@ -0,0 +1,70 @@
+use std::fmt;
+
+use common::{parse_lines, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day01;
+
+/// A line of the calibration document, numbered from 1, without a digit to
+/// take the calibration value from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoDigit(pub usize);
+
+impl fmt::Display for NoDigit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} has no digit", self.0)
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    /// The lines of the calibration document.
+    type Input = Vec<String>;
+    type Params = ();
+    type Answer1 = Result<u64, NoDigit>;
+    type Answer2 = Result<u64, NoDigit>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            if line.is_empty() {
+                Err(ParseError::new(
+                    line,
+                    line,
+                    "a line of the calibration document",
+                ))
+            } else {
+                Ok(line.to_string())
+            }
+        })
+    }
+
+    fn part1(lines: &Self::Input, _params: &()) -> Result<u64, NoDigit> {
+        part1::solve(lines)
+    }
+
+    fn part2(lines: &Self::Input, _params: &()) -> Result<u64, NoDigit> {
+        part2::solve(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_without_digits() {
+        let err = Day01::parse("a1b\n\nc2\n").unwrap_err();
+        assert_eq!(err.line, 2);
+
+        let lines = Day01::parse("a1b\nnine\n").unwrap();
+        assert_eq!(Day01::part1(&lines, &()), Err(NoDigit(2)));
+        assert_eq!(Day01::part2(&lines, &()), Ok(11 + 99));
+        let lines = Day01::parse("a١b\n").unwrap();
+        assert_eq!(Day01::part2(&lines, &()), Err(NoDigit(1)));
+    }
+}
+
+common::examples!(Day01);
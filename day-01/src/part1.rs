@@ -0,0 +1,16 @@
+use crate::NoDigit;
+
+pub fn solve(lines: &[String]) -> Result<u64, NoDigit> {
+    lines
+        .iter()
+        .zip(1..)
+        .map(|(line, i)| get_calibration_number(line).ok_or(NoDigit(i)))
+        .sum()
+}
+
+fn get_calibration_number(line: &str) -> Option<u64> {
+    let mut digits = line.chars().filter_map(|ch| ch.to_digit(10));
+    let first = digits.next()?;
+    let last = digits.next_back().unwrap_or(first);
+    Some(u64::from(first * 10 + last))
+}
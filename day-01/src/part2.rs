@@ -0,0 +1,84 @@
+use crate::NoDigit;
+
+pub fn solve(lines: &[String]) -> Result<u64, NoDigit> {
+    lines
+        .iter()
+        .zip(1..)
+        .map(|(line, i)| get_calibration_number(line).ok_or(NoDigit(i)))
+        .sum()
+}
+
+fn get_calibration_number(line: &str) -> Option<u64> {
+    let first = line.char_indices().find_map(|(i, ch)| {
+        ch.to_digit(10)
+            .or_else(|| spelled_number_at_start(&line[i..]))
+    })?;
+    let last = line.char_indices().rev().find_map(|(i, ch)| {
+        ch.to_digit(10)
+            .or_else(|| spelled_number_at_end(&line[..i + ch.len_utf8()]))
+    })?;
+    Some(u64::from(first * 10 + last))
+}
+
+fn spelled_number_at_start(string: &str) -> Option<u32> {
+    if string.starts_with("zero") {
+        Some(0)
+    } else if string.starts_with("one") {
+        Some(1)
+    } else if string.starts_with("two") {
+        Some(2)
+    } else if string.starts_with("three") {
+        Some(3)
+    } else if string.starts_with("four") {
+        Some(4)
+    } else if string.starts_with("five") {
+        Some(5)
+    } else if string.starts_with("six") {
+        Some(6)
+    } else if string.starts_with("seven") {
+        Some(7)
+    } else if string.starts_with("eight") {
+        Some(8)
+    } else if string.starts_with("nine") {
+        Some(9)
+    } else {
+        None
+    }
+}
+
+fn spelled_number_at_end(string: &str) -> Option<u32> {
+    if string.ends_with("zero") {
+        Some(0)
+    } else if string.ends_with("one") {
+        Some(1)
+    } else if string.ends_with("two") {
+        Some(2)
+    } else if string.ends_with("three") {
+        Some(3)
+    } else if string.ends_with("four") {
+        Some(4)
+    } else if string.ends_with("five") {
+        Some(5)
+    } else if string.ends_with("six") {
+        Some(6)
+    } else if string.ends_with("seven") {
+        Some(7)
+    } else if string.ends_with("eight") {
+        Some(8)
+    } else if string.ends_with("nine") {
+        Some(9)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(spelled_number_at_start("sevenabc"), Some(7));
+        assert_eq!(spelled_number_at_end("abcseven"), Some(7));
+    }
+}
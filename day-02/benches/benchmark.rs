@@ -0,0 +1,15 @@
+extern crate day_02;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_02::Day02;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day02::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part1", |b| {
+        b.iter(|| Day02::part1(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
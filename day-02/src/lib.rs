@@ -0,0 +1,104 @@
+use common::{parse_lines, parse_number, split_once, ParseError, Solution};
+use regex::Regex;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ();
+
+    fn try_from(color: &str) -> Result<Self, Self::Error> {
+        match color {
+            "red" => Ok(Color::Red),
+            "green" => Ok(Color::Green),
+            "blue" => Ok(Color::Blue),
+            _ => Err(()),
+        }
+    }
+}
+
+/// One record like `Game 86: 8 blue, 9 green; 1 red`, with all reveals of
+/// all sets flattened into `(number, color)` pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub reveals: Vec<(u32, Color)>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(record: &str) -> Result<Self, Self::Error> {
+        let reveals = split_once(record, record, ":")?
+            .1
+            .split([',', ';'])
+            .map(|reveal| {
+                let (num, color) = split_once(record, reveal.trim_start(), " ")?;
+                let color = Color::try_from(color)
+                    .map_err(|_| ParseError::new(record, color, "`red`, `green` or `blue`"))?;
+                Ok((parse_number(record, num)?, color))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Game {
+            id: get_game_id(record)?,
+            reveals,
+        })
+    }
+}
+
+fn get_game_id(record: &str) -> Result<u32, ParseError> {
+    let re = Regex::new(r"Game (\d+)").unwrap();
+    let id = re
+        .captures(record)
+        .and_then(|captures| captures.get(1))
+        .ok_or_else(|| ParseError::new(record, record, "`Game <id>`"))?;
+    parse_number(record, id.as_str())
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Game::try_from)
+    }
+
+    fn part1(games: &Self::Input, _params: &()) -> u32 {
+        part1::solve(games)
+    }
+
+    fn part2(games: &Self::Input, _params: &()) -> u32 {
+        part2::solve(games)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id() {
+        assert_eq!(get_game_id("Game 86: 8 blue, 9 green"), Ok(86));
+    }
+
+    #[test]
+    fn test_invalid_color() {
+        let err = Game::try_from("Game 1: 3 blue, 4 rad").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (19, "rad"));
+    }
+}
+
+common::examples!(Day02);
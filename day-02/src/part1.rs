@@ -0,0 +1,19 @@
+use crate::{Color, Game};
+
+pub fn solve(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| {
+            let impossible = game.reveals.iter().any(|&(num, color)| match color {
+                Color::Red => num > 12,
+                Color::Green => num > 13,
+                Color::Blue => num > 14,
+            });
+            if !impossible {
+                game.id
+            } else {
+                0
+            }
+        })
+        .sum::<u32>()
+}
@@ -0,0 +1,44 @@
+use std::cmp::max;
+
+use crate::{Color, Game};
+
+#[derive(Default)]
+struct Set {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+pub fn solve(games: &[Game]) -> u32 {
+    games.iter().map(calc_power).sum::<u32>()
+}
+
+fn calc_power(game: &Game) -> u32 {
+    let fewest: Set = game
+        .reveals
+        .iter()
+        .fold(Set::default(), |mut acc, &(num, color)| {
+            match color {
+                Color::Red => acc.red = max(acc.red, num),
+                Color::Green => acc.green = max(acc.green, num),
+                Color::Blue => acc.blue = max(acc.blue, num),
+            }
+            acc
+        });
+    fewest.red * fewest.green * fewest.blue
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power() {
+        assert_eq!(
+            calc_power(
+                &Game::try_from("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap()
+            ),
+            48
+        )
+    }
+}
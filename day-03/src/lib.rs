@@ -0,0 +1,160 @@
+use std::cmp::min;
+
+use common::{check_chars, parse_lines, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day03;
+
+/// The engine schematic together with every number found in it.
+pub struct Schematic {
+    pub text: String,
+    pub numbers: Vec<Number>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    pub line_index: usize,
+    pub pos: usize,
+    pub len: usize,
+}
+
+impl Number {
+    pub fn new(line_index: usize, pos: usize) -> Number {
+        Number {
+            line_index,
+            pos,
+            len: 1,
+        }
+    }
+
+    pub fn get_end(&self) -> usize {
+        self.pos + self.len
+    }
+
+    pub fn increase(&mut self) {
+        self.len += 1;
+    }
+
+    pub fn is_valid(&self, input: &str) -> bool {
+        match self.line_index {
+            0 => input.lines().take(2).any(|line| {
+                line[self.pos.saturating_sub(1)..min(self.pos + self.len + 1, line.len())]
+                    .chars()
+                    .any(|ch| !ch.is_numeric() && ch != '.')
+            }),
+            _ => input.lines().skip(self.line_index - 1).take(3).any(|line| {
+                line[self.pos.saturating_sub(1)..min(self.pos + self.len + 1, line.len())]
+                    .chars()
+                    .any(|ch| !ch.is_numeric() && ch != '.')
+            }),
+        }
+    }
+
+    pub fn get_sum(&self, input: &str) -> usize {
+        input
+            .lines()
+            .skip(self.line_index)
+            .take(1)
+            .collect::<Vec<&str>>()[0][self.pos..self.pos + self.len]
+            .parse()
+            .unwrap()
+    }
+
+    pub fn get_valid_sum(&self, input: &str) -> usize {
+        if self.is_valid(input) {
+            self.get_sum(input)
+        } else {
+            0
+        }
+    }
+}
+
+pub fn find_numbers(line: &str, line_index: usize) -> Vec<Number> {
+    line.char_indices()
+        .filter(|(_, ch)| ch.is_alphanumeric())
+        .fold(Vec::new(), |mut acc, (pos, _)| {
+            match acc.last_mut() {
+                Some(last) if last.get_end() == pos => {
+                    last.increase();
+                }
+                _ => acc.push(Number::new(line_index, pos)),
+            }
+            acc
+        })
+}
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            check_chars(
+                line,
+                line,
+                |c| !c.is_alphabetic(),
+                "a digit, `.` or a symbol",
+            )
+        })?;
+        Ok(Schematic {
+            text: input.to_string(),
+            numbers: input
+                .lines()
+                .zip(0..)
+                .flat_map(|(line, line_index)| find_numbers(line, line_index))
+                .collect(),
+        })
+    }
+
+    fn part1(schematic: &Self::Input, _params: &()) -> usize {
+        part1::solve(schematic)
+    }
+
+    fn part2(schematic: &Self::Input, _params: &()) -> usize {
+        part2::solve(schematic)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_numbers() {
+        assert_eq!(
+            find_numbers("...%.564..", 0),
+            vec![Number {
+                line_index: 0,
+                pos: 5,
+                len: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn test_find_numbers_start_and_end() {
+        assert_eq!(
+            find_numbers("54%.564", 0),
+            vec![
+                Number {
+                    line_index: 0,
+                    pos: 0,
+                    len: 2
+                },
+                Number {
+                    line_index: 0,
+                    pos: 4,
+                    len: 3
+                }
+            ]
+        );
+    }
+}
+
+common::examples!(Day03);
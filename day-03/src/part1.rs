@@ -0,0 +1,9 @@
+use crate::Schematic;
+
+pub fn solve(schematic: &Schematic) -> usize {
+    schematic
+        .numbers
+        .iter()
+        .map(|number| number.get_valid_sum(&schematic.text))
+        .sum()
+}
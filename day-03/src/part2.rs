@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use crate::{Number, Schematic};
+
+pub fn solve(schematic: &Schematic) -> usize {
+    let input = &schematic.text;
+    let line_len = input.lines().next().unwrap().len();
+    let numbers_map: HashMap<usize, &Number> = schematic
+        .numbers
+        .iter()
+        .map(|number| (line_len * number.line_index + number.pos, number))
+        .collect();
+
+    let mut keys = numbers_map.keys().collect::<Vec<&usize>>();
+    keys.sort();
+
+    let gear_values: usize = input
+        .lines()
+        .zip(0_usize..)
+        .map(|(line, line_index)| {
+            line.char_indices()
+                .filter(|(_, ch)| *ch == '*')
+                .map(|(pos, _)| {
+                    let lines = input.lines().skip(line_index.saturating_sub(1));
+                    let relevant_lines = if line_index == 0 {
+                        lines.take(2)
+                    } else {
+                        lines.take(3)
+                    };
+                    let adjacent_parts: Vec<&Number> = relevant_lines
+                        .zip(if line_index == 0 {
+                            0..
+                        } else {
+                            line_index - 1..
+                        })
+                        .flat_map(|(line, line_index)| {
+                            // Mid
+                            let mut adjacent_parts: Vec<&Number> = Vec::new();
+                            let pos_global = line.len() * line_index + pos;
+                            if keys.contains(&&pos_global) {
+                                adjacent_parts.push(*numbers_map.get(&pos_global).unwrap());
+                            }
+                            // if mid contains first digit of part number, both left and right cannot be start of part numbers
+                            else {
+                                // check right side only if not at the end of line
+                                if pos_global % line.len() <= line.len() - 2
+                                    && keys.contains(&&(pos_global + 1))
+                                {
+                                    adjacent_parts
+                                        .push(*numbers_map.get(&(pos_global + 1)).unwrap());
+                                }
+                                // check if left is part number
+                                if pos_global % line.len() > 1
+                                    && line.chars().collect::<Vec<char>>()[pos - 1].is_numeric()
+                                {
+                                    let index = keys.binary_search(&&(pos_global - 1));
+                                    if index.is_ok() {
+                                        // left is start of part number
+                                        adjacent_parts
+                                            .push(*numbers_map.get(&(pos_global - 1)).unwrap());
+                                    } else {
+                                        let index = index.err().unwrap() - 1;
+                                        let key = keys.get(index).unwrap();
+                                        let number = *numbers_map.get(key).unwrap();
+                                        adjacent_parts.push(number);
+                                    }
+                                }
+                            }
+                            adjacent_parts
+                        })
+                        .filter(|part| part.is_valid(input))
+                        .collect();
+                    if adjacent_parts.len() == 2 {
+                        adjacent_parts.first().unwrap().get_sum(input)
+                            * adjacent_parts.get(1).unwrap().get_sum(input)
+                    } else {
+                        0
+                    }
+                })
+                .sum::<usize>()
+        })
+        .sum();
+    gear_values
+}
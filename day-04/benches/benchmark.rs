@@ -0,0 +1,15 @@
+extern crate day_04;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_04::Day04;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day04::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day04::part2(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use common::{parse_lines, parse_number, split_once, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day04;
+
+/// One scratchcard, e.g. `Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Card {
+    pub winning: Vec<u8>,
+    pub mine: Vec<u8>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (winning, mine) = split_once(line, split_once(line, line, ":")?.1, "|")?;
+        let numbers = |numbers: &str| {
+            numbers
+                .split_whitespace()
+                .map(|num| parse_number(line, num))
+                .collect::<Result<Vec<u8>, _>>()
+        };
+        Ok(Card {
+            winning: numbers(winning)?,
+            mine: numbers(mine)?,
+        })
+    }
+}
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Params = ();
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Card::try_from)
+    }
+
+    fn part1(cards: &Self::Input, _params: &()) -> u32 {
+        part1::solve(cards)
+    }
+
+    fn part2(cards: &Self::Input, _params: &()) -> usize {
+        part2::solve(cards)
+    }
+}
+
+common::examples!(Day04);
@@ -0,0 +1,28 @@
+use std::cmp::max;
+
+use crate::Card;
+
+pub fn solve(cards: &[Card]) -> u32 {
+    cards.iter().map(process_card).sum()
+}
+
+fn process_card(card: &Card) -> u32 {
+    card.mine.iter().fold(0, |acc, num| {
+        if card.winning.contains(num) {
+            max(acc * 2, 1)
+        } else {
+            acc
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linetest() {
+        let line = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        assert_eq!(process_card(&Card::try_from(line).unwrap()), 8)
+    }
+}
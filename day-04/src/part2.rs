@@ -0,0 +1,34 @@
+use std::collections::HashSet;
+
+use crate::Card;
+
+pub fn solve(cards: &[Card]) -> usize {
+    let card_values: Vec<usize> = cards.iter().map(process_card).collect();
+    let mut owned_cards = vec![1; card_values.len()];
+
+    card_values.iter().enumerate().for_each(|(id, card_value)| {
+        let num_current_card = *owned_cards.get(id).unwrap();
+        for i in id + 1..id + 1 + card_value {
+            let next_card = owned_cards.get_mut(i).unwrap();
+            *next_card += num_current_card;
+        }
+    });
+
+    owned_cards.iter().sum()
+}
+
+fn process_card(card: &Card) -> usize {
+    let winning: HashSet<&u8> = card.winning.iter().collect();
+    card.mine.iter().filter(|num| winning.contains(num)).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linetest() {
+        let line = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        assert_eq!(process_card(&Card::try_from(line).unwrap()), 4)
+    }
+}
@@ -0,0 +1,15 @@
+extern crate day_05;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_05::Day05;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day05::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day05::part2(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
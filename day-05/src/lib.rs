@@ -0,0 +1,685 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{parse_number, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day05;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct Range {
+    pub from: usize,
+    pub len: usize,
+}
+
+impl Range {
+    pub fn new(from: usize, len: usize) -> Range {
+        Range { from, len }
+    }
+
+    pub fn end(&self) -> usize {
+        self.from + self.len - 1
+    }
+}
+
+/// A set of numbers in canonical form: sorted ranges, none of them empty,
+/// with a gap between each and the next. Two sets are equal exactly if they
+/// hold the same numbers.
+#[derive(PartialEq, Eq, Debug, Clone, Default)]
+pub struct RangeSet(Vec<Range>);
+
+impl RangeSet {
+    pub fn ranges(&self) -> &[Range] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn min(&self) -> Option<usize> {
+        self.0.first().map(|range| range.from)
+    }
+
+    pub fn contains(&self, number: usize) -> bool {
+        let at = self.0.partition_point(|range| range.end() < number);
+        self.0.get(at).is_some_and(|range| range.from <= number)
+    }
+}
+
+/// Sorts the ranges, drops empty ones and merges the ones that overlap or
+/// touch.
+impl FromIterator<Range> for RangeSet {
+    fn from_iter<T: IntoIterator<Item = Range>>(ranges: T) -> Self {
+        let mut ranges: Vec<Range> = ranges.into_iter().filter(|range| range.len > 0).collect();
+        ranges.sort_by_key(|range| range.from);
+        let mut merged: Vec<Range> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.from <= last.from + last.len => {
+                    last.len = last.len.max(range.from + range.len - last.from);
+                }
+                _ => merged.push(range),
+            }
+        }
+        RangeSet(merged)
+    }
+}
+
+/// Maps the `len` numbers from `src` on to the ones from `dest`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Segment {
+    src: usize,
+    dest: usize,
+    len: usize,
+}
+
+impl Segment {
+    /// First number after the segment.
+    fn src_end(&self) -> usize {
+        self.src + self.len
+    }
+
+    fn get(&self, src: usize) -> usize {
+        src - self.src + self.dest
+    }
+
+    /// Whether `next` continues this segment in both source and destination.
+    fn continues(&self, next: &Segment) -> bool {
+        self.src_end() == next.src && self.dest + self.len == next.dest
+    }
+}
+
+/// One `x-to-y map:` block of the almanac, or several of them composed: a
+/// piecewise-linear function that keeps every number not covered by one of
+/// its sorted, non-overlapping segments.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RangeMap {
+    segments: Vec<Segment>,
+}
+
+impl RangeMap {
+    pub fn new() -> RangeMap {
+        RangeMap::default()
+    }
+
+    /// Maps `range` numbers from `src` on to `dest`. Returns false, leaving
+    /// the map as it is, if they overlap numbers that are mapped already.
+    pub fn insert(&mut self, src: usize, dest: usize, range: usize) -> bool {
+        let segment = Segment {
+            src,
+            dest,
+            len: range,
+        };
+        let at = self.segments.partition_point(|other| other.src < src);
+        let overlaps = self
+            .segments
+            .get(at)
+            .is_some_and(|next| next.src < segment.src_end())
+            || at > 0 && self.segments[at - 1].src_end() > src;
+        if overlaps {
+            return false;
+        }
+        if range > 0 && src != dest {
+            self.segments.insert(at, segment);
+            self.merge_around(at);
+        }
+        true
+    }
+
+    /// Merges the segment at `at` with its neighbours it continues.
+    fn merge_around(&mut self, at: usize) {
+        if at + 1 < self.segments.len() && self.segments[at].continues(&self.segments[at + 1]) {
+            self.segments[at].len += self.segments.remove(at + 1).len;
+        }
+        if at > 0 && self.segments[at - 1].continues(&self.segments[at]) {
+            self.segments[at - 1].len += self.segments.remove(at).len;
+        }
+    }
+
+    /// Appends a segment after all others, dropping it if it keeps the
+    /// numbers and merging it with the last one if it continues that.
+    fn push(&mut self, segment: Segment) {
+        if segment.len == 0 || segment.src == segment.dest {
+            return;
+        }
+        match self.segments.last_mut() {
+            Some(last) if last.continues(&segment) => last.len += segment.len,
+            _ => self.segments.push(segment),
+        }
+    }
+
+    pub fn get(&self, src: usize) -> usize {
+        let at = self
+            .segments
+            .partition_point(|segment| segment.src_end() <= src);
+        match self.segments.get(at) {
+            Some(segment) if segment.src <= src => segment.get(src),
+            _ => src,
+        }
+    }
+
+    /// The pieces `len` numbers from `from` fall into, including the ones
+    /// between segments that map to themselves, in order.
+    fn split(&self, from: usize, len: usize) -> Vec<Segment> {
+        let end = from + len;
+        let mut pieces = Vec::new();
+        let mut at = from;
+        let mut i = self
+            .segments
+            .partition_point(|segment| segment.src_end() <= from);
+        while at < end {
+            let piece = match self.segments.get(i) {
+                Some(segment) if segment.src <= at => {
+                    i += 1;
+                    let len = segment.src_end().min(end) - at;
+                    Segment {
+                        src: at,
+                        dest: segment.get(at),
+                        len,
+                    }
+                }
+                next => {
+                    let len = next.map_or(end, |next| next.src.min(end)) - at;
+                    Segment {
+                        src: at,
+                        dest: at,
+                        len,
+                    }
+                }
+            };
+            at += piece.len;
+            pieces.push(piece);
+        }
+        pieces
+    }
+
+    /// The map that applies this one and then `then`. It has at most as many
+    /// segments as both together have boundaries.
+    pub fn compose(&self, then: &RangeMap) -> RangeMap {
+        let mut composed = RangeMap::new();
+        for piece in self.split(0, usize::MAX) {
+            for part in then.split(piece.dest, piece.len) {
+                composed.push(Segment {
+                    src: piece.src + (part.src - piece.dest),
+                    dest: part.dest,
+                    len: part.len,
+                });
+            }
+        }
+        composed
+    }
+
+    /// Where the numbers in `ranges` end up.
+    pub fn translate_ranges(&self, ranges: &RangeSet) -> RangeSet {
+        ranges
+            .ranges()
+            .iter()
+            .flat_map(|range| self.split(range.from, range.len))
+            .map(|piece| Range::new(piece.dest, piece.len))
+            .collect()
+    }
+
+    /// Every number that ends up in `ranges`.
+    pub fn preimage(&self, ranges: &RangeSet) -> RangeSet {
+        let ranges = ranges.ranges();
+        let mut preimage = Vec::new();
+        for piece in self.split(0, usize::MAX) {
+            let image_end = piece.dest + piece.len;
+            // The first range that does not end before the image.
+            let first = ranges.partition_point(|range| range.from + range.len <= piece.dest);
+            for range in ranges[first..]
+                .iter()
+                .take_while(|range| range.from < image_end)
+            {
+                let from = range.from.max(piece.dest);
+                let end = (range.from + range.len).min(image_end);
+                preimage.push(Range::new(piece.src + (from - piece.dest), end - from));
+            }
+        }
+        preimage.into_iter().collect()
+    }
+}
+
+/// One `<from>-to-<to> map:` block of the almanac.
+#[derive(Debug, Clone)]
+pub struct Mapping {
+    pub from: String,
+    pub to: String,
+    pub map: RangeMap,
+}
+
+/// The seeds line and the maps between categories, in whatever order they
+/// are listed. Together they form a graph that can be walked between any two
+/// categories, against the direction of a map as well.
+#[derive(Debug, Clone)]
+pub struct Almanac {
+    pub seeds: Vec<usize>,
+    pub maps: Vec<Mapping>,
+}
+
+impl Almanac {
+    /// The fewest maps leading from category `from` to `to`, each with
+    /// whether it is walked forward, against its direction as well if
+    /// `backwards`. `None` if they are not connected.
+    fn path(&self, from: &str, to: &str, backwards: bool) -> Option<Vec<(&RangeMap, bool)>> {
+        // How each category was reached: the index of the map and whether it
+        // was walked forward.
+        let mut reached: HashMap<&str, Option<(usize, bool)>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+            for (i, mapping) in self.maps.iter().enumerate() {
+                let next = if mapping.from == category {
+                    (mapping.to.as_str(), true)
+                } else if backwards && mapping.to == category {
+                    (mapping.from.as_str(), false)
+                } else {
+                    continue;
+                };
+                if !reached.contains_key(next.0) {
+                    reached.insert(next.0, Some((i, next.1)));
+                    queue.push_back(next.0);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut category = to;
+        while let Some((i, forward)) = *reached.get(category)? {
+            let mapping = &self.maps[i];
+            path.push((&mapping.map, forward));
+            category = if forward { &mapping.from } else { &mapping.to };
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The maps from category `from` to `to` composed into one. `None` if
+    /// there is no way from one to the other that follows the maps forward.
+    pub fn map(&self, from: &str, to: &str) -> Option<RangeMap> {
+        let path = self.path(from, to, false)?;
+        Some(
+            path.into_iter()
+                .fold(RangeMap::new(), |chain, (map, _)| chain.compose(map)),
+        )
+    }
+
+    /// All maps from seed to location composed into one, checked to exist
+    /// when parsing.
+    pub fn seed_to_location(&self) -> RangeMap {
+        self.map("seed", "location")
+            .expect("maps from seed to location")
+    }
+
+    /// Where the numbers in `ranges` of category `from` end up in category
+    /// `to`, along the maps if they lead there, as [`Almanac::map`] does, and
+    /// otherwise against them as well. Walking a map backwards gives every
+    /// number that maps to them, e.g. the seeds for a location below 100:
+    ///
+    /// ```
+    /// # use common::Solution;
+    /// # use day_05::{Day05, Range, RangeSet};
+    /// # let almanac = Day05::parse(include_str!("../res/examples/example")).unwrap();
+    /// let locations = RangeSet::from_iter([Range::new(0, 100)]);
+    /// let seeds = almanac.translate("location", "seed", &locations);
+    /// ```
+    ///
+    /// `None` if the categories are not connected.
+    pub fn translate(&self, from: &str, to: &str, ranges: &RangeSet) -> Option<RangeSet> {
+        let path = self
+            .path(from, to, false)
+            .or_else(|| self.path(from, to, true))?;
+        Some(
+            path.into_iter()
+                .fold(ranges.clone(), |ranges, (map, forward)| {
+                    if forward {
+                        map.translate_ranges(&ranges)
+                    } else {
+                        map.preimage(&ranges)
+                    }
+                }),
+        )
+    }
+}
+
+impl TryFrom<&str> for Almanac {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = input.lines();
+
+        let first = lines.next().ok_or(ParseError::missing("a `seeds:` line"))?;
+        let seeds = first
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::new(first, first, "`seeds:`"))?;
+        let tokens: Vec<&str> = seeds.split_whitespace().collect();
+        // Part 2 reads the seeds as pairs of a start and a length.
+        if tokens.is_empty() || tokens.len() % 2 == 1 {
+            let end = &seeds[seeds.len()..];
+            return Err(ParseError::new(first, end, "pairs of seed numbers"));
+        }
+        let seeds: Vec<usize> = tokens
+            .iter()
+            .map(|seed| parse_number(first, seed))
+            .collect::<Result<_, _>>()?;
+        if let Some(pair) = seeds.chunks_exact(2).position(|pair| pair[1] == 0) {
+            let len = tokens[2 * pair + 1];
+            return Err(ParseError::new(first, len, "a range of at least one seed"));
+        }
+
+        let mut maps: Vec<Mapping> = Vec::new();
+
+        for (i, line) in lines.enumerate() {
+            if let Some(header) = line.strip_suffix(" map:") {
+                let (from, to) = header.split_once("-to-").ok_or_else(|| {
+                    ParseError::new(line, header, "`<from>-to-<to>`").offset(i + 1)
+                })?;
+                if maps
+                    .iter()
+                    .any(|mapping| mapping.from == from && mapping.to == to)
+                {
+                    return Err(
+                        ParseError::new(line, header, "a map not listed before").offset(i + 1)
+                    );
+                }
+                maps.push(Mapping {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                    map: RangeMap::new(),
+                });
+            } else if !line.is_empty() {
+                let numbers = line
+                    .split_whitespace()
+                    .map(|number| parse_number(line, number))
+                    .collect::<Result<Vec<usize>, _>>()
+                    .map_err(|err| err.offset(i + 1))?;
+                let [dest, source, range]: [usize; 3] = numbers
+                    .try_into()
+                    .map_err(|_| ParseError::new(line, line, "three numbers").offset(i + 1))?;
+
+                let mapping = maps
+                    .last_mut()
+                    .ok_or_else(|| ParseError::new(line, line, "a `map:` header").offset(i + 1))?;
+                if !mapping.map.insert(source, dest, range) {
+                    return Err(
+                        ParseError::new(line, line, "a range not overlapping the others")
+                            .offset(i + 1),
+                    );
+                }
+            }
+        }
+        Ok(Almanac { seeds, maps })
+    }
+}
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let almanac = Almanac::try_from(input)?;
+        if almanac.map("seed", "location").is_none() {
+            return Err(ParseError::missing("maps from seed to location"));
+        }
+        Ok(almanac)
+    }
+
+    fn part1(almanac: &Self::Input, _params: &()) -> usize {
+        part1::solve(almanac)
+    }
+
+    fn part2(almanac: &Self::Input, _params: &()) -> usize {
+        part2::solve(almanac)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn set(ranges: &[(usize, usize)]) -> RangeSet {
+        ranges
+            .iter()
+            .map(|&(from, len)| Range::new(from, len))
+            .collect()
+    }
+
+    fn map(segments: &[(usize, usize, usize)]) -> RangeMap {
+        let mut map = RangeMap::new();
+        for &(src, dest, range) in segments {
+            assert!(map.insert(src, dest, range));
+        }
+        map
+    }
+
+    #[test]
+    fn map_get() {
+        let map = map(&[(98, 50, 2), (50, 52, 48)]);
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(14), 14);
+        assert_eq!(map.get(98), 50);
+        assert_eq!(map.get(100), 100);
+    }
+
+    #[test]
+    fn map_insert() {
+        let mut map = map(&[(50, 60, 20)]);
+        assert!(!map.insert(60, 0, 20));
+        assert!(!map.insert(40, 0, 11));
+        assert!(map.insert(40, 0, 10));
+        // Continues the first segment.
+        assert!(map.insert(70, 80, 5));
+        assert_eq!(map, self::map(&[(40, 0, 10), (50, 60, 25)]));
+    }
+
+    #[test]
+    fn map_get_ranges() {
+        let map = map(&[(50, 60, 20)]);
+        assert_eq!(map.translate_ranges(&set(&[(55, 10)])), set(&[(65, 10)]));
+    }
+
+    #[test]
+    fn map_get_ranges_overlapping() {
+        let map = map(&[(50, 60, 20)]);
+        // 70..75 maps to itself, inside 65..80.
+        assert_eq!(map.translate_ranges(&set(&[(55, 20)])), set(&[(65, 15)]));
+    }
+
+    #[test]
+    fn map_get_ranges_overlapping_both() {
+        let map = map(&[(50, 60, 1)]);
+        assert_eq!(
+            map.translate_ranges(&set(&[(45, 10)])),
+            set(&[(45, 5), (51, 4), (60, 1)])
+        );
+    }
+
+    #[test]
+    fn map_get_ranges_same() {
+        let map = map(&[(50, 60, 20)]);
+        assert_eq!(map.translate_ranges(&set(&[(50, 20)])), set(&[(60, 20)]));
+    }
+
+    #[test]
+    fn map_get_ranges_one_off() {
+        let map = map(&[(50, 60, 20)]);
+        assert_eq!(
+            map.translate_ranges(&set(&[(49, 22)])),
+            set(&[(49, 1), (60, 20)])
+        );
+    }
+
+    #[test]
+    fn map_compose() {
+        let first = map(&[(98, 50, 2), (50, 52, 48)]);
+        let then = map(&[(15, 0, 37), (52, 37, 2), (0, 39, 15)]);
+        let composed = first.compose(&then);
+        for src in 0..120 {
+            assert_eq!(composed.get(src), then.get(first.get(src)), "{src}");
+        }
+        assert_eq!(composed.get(usize::MAX - 1), usize::MAX - 1);
+    }
+
+    #[test]
+    fn map_preimage() {
+        let map = map(&[(98, 50, 2), (50, 52, 48)]);
+        // 50 and 51 are both kept by the gap and mapped to from 98 and 99.
+        assert_eq!(map.preimage(&set(&[(50, 2)])), set(&[(98, 2)]));
+        assert_eq!(
+            map.preimage(&set(&[(49, 2), (99, 3)])),
+            set(&[(49, 1), (97, 2), (100, 2)])
+        );
+    }
+
+    fn example() -> Almanac {
+        Day05::parse(&common::normalize(include_str!("../res/examples/example"))).unwrap()
+    }
+
+    #[test]
+    fn categories_in_any_order() {
+        let text = "seeds: 79 1\n\nsoil-to-location map:\n0 50 10\n\nseed-to-soil map:\n50 70 20\n";
+        let almanac = Day05::parse(text).unwrap();
+        assert_eq!(almanac.seed_to_location().get(79), 9);
+        assert!(almanac.map("location", "seed").is_none());
+        assert!(almanac.translate("seed", "water", &set(&[])).is_none());
+        assert_eq!(
+            Day05::parse("seeds: 79 1\n\nseed-to-soil map:\n50 70 20\n")
+                .unwrap_err()
+                .expected,
+            "maps from seed to location"
+        );
+    }
+
+    #[test]
+    fn map_only_forward() {
+        // Walking `c-to-a` backwards is the short way from `a` to `c`, but
+        // the way through `b` follows the maps, so both queries take it.
+        let text = "seeds: 1 1\n\nseed-to-location map:\n0 0 1\n\n\
+                    a-to-b map:\n10 0 5\n\nb-to-c map:\n20 10 5\n\nc-to-a map:\n0 100 5\n";
+        let almanac = Day05::parse(text).unwrap();
+        assert_eq!(almanac.map("a", "c").unwrap().get(3), 23);
+        assert_eq!(almanac.map("c", "b").unwrap().get(101), 11);
+        assert!(almanac.map("b", "a").is_some());
+        assert_eq!(
+            almanac.translate("a", "c", &set(&[(3, 1)])),
+            Some(set(&[(23, 1)]))
+        );
+        // Without a way along the maps, `translate` walks against them.
+        let text = "seeds: 1 1\n\nseed-to-location map:\n0 0 1\n\n\
+                    a-to-b map:\n10 0 5\n\nc-to-b map:\n10 100 5\n";
+        let almanac = Day05::parse(text).unwrap();
+        assert!(almanac.map("a", "c").is_none());
+        assert_eq!(
+            almanac.translate("a", "c", &set(&[(3, 1)])),
+            Some(set(&[(13, 1), (103, 1)]))
+        );
+    }
+
+    #[test]
+    fn seed_pairs() {
+        let maps = "\n\nseed-to-location map:\n50 70 20\n";
+        let err = Day05::parse(&format!("seeds: 79 14 55{maps}")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 16));
+        let err = Day05::parse(&format!("seeds:{maps}")).unwrap_err();
+        assert_eq!(err.expected, "pairs of seed numbers");
+        let err = Day05::parse(&format!("seeds: 79 14 55 0{maps}")).unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (17, "0"));
+    }
+
+    #[test]
+    fn query_backwards() {
+        let almanac = example();
+        // Fertilizer 42 comes from soil 3 only.
+        assert_eq!(
+            almanac.translate("fertilizer", "soil", &set(&[(42, 1)])),
+            Some(set(&[(3, 1)]))
+        );
+    }
+
+    #[test]
+    fn seeds_for_locations() {
+        let almanac = example();
+        let seed_to_location = almanac.seed_to_location();
+        let seeds = almanac
+            .translate("location", "seed", &set(&[(0, 50)]))
+            .unwrap();
+        for seed in 0..150 {
+            assert_eq!(
+                seeds.contains(seed),
+                seed_to_location.get(seed) < 50,
+                "{seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn canonical_sets() {
+        let merged = set(&[(0, 5), (10, 5)]);
+        assert_eq!(set(&[(10, 5), (0, 3), (7, 0), (3, 2), (12, 1)]), merged);
+        assert_eq!(merged.ranges(), [Range::new(0, 5), Range::new(10, 5)]);
+        assert!(merged.contains(4) && !merged.contains(5) && merged.contains(14));
+        assert!(set(&[(3, 0)]).is_empty());
+    }
+
+    /// Maps with up to a few segments among small numbers.
+    fn small_map() -> impl Strategy<Value = RangeMap> {
+        prop::collection::vec((0..60usize, 0..60usize, 0..15usize), 0..5).prop_map(|segments| {
+            let mut map = RangeMap::new();
+            for (src, dest, range) in segments {
+                // Overlapping segments are left out.
+                map.insert(src, dest, range);
+            }
+            map
+        })
+    }
+
+    fn small_set() -> impl Strategy<Value = RangeSet> {
+        prop::collection::vec((0..80usize, 0..20usize), 0..4).prop_map(|ranges| set(&ranges))
+    }
+
+    proptest! {
+        #[test]
+        fn translated_one_by_one(map in small_map(), ranges in small_set()) {
+            let numbers = ranges.ranges().iter().flat_map(|range| range.from..range.from + range.len);
+            let expected = set(&numbers.map(|number| (map.get(number), 1)).collect::<Vec<_>>());
+            prop_assert_eq!(map.translate_ranges(&ranges), expected);
+        }
+
+        #[test]
+        fn preimage_one_by_one(map in small_map(), ranges in small_set()) {
+            let preimage = map.preimage(&ranges);
+            for number in 0..120 {
+                prop_assert_eq!(preimage.contains(number), ranges.contains(map.get(number)));
+            }
+        }
+
+        #[test]
+        fn composed_one_by_one(first in small_map(), then in small_map()) {
+            let composed = first.compose(&then);
+            for number in 0..120 {
+                prop_assert_eq!(composed.get(number), then.get(first.get(number)));
+            }
+        }
+
+        /// Part 2 on seed ranges finds what part 1 finds on every seed in them.
+        #[test]
+        fn part2_matches_part1(seeds in prop::collection::vec((0..100usize, 1..30usize), 1..4)) {
+            let mut almanac = example();
+            almanac.seeds = seeds.iter().flat_map(|&(from, len)| [from, len]).collect();
+            let ranges = part2::solve(&almanac);
+            almanac.seeds = seeds.iter().flat_map(|&(from, len)| from..from + len).collect();
+            prop_assert_eq!(ranges, part1::solve(&almanac));
+        }
+    }
+}
+
+common::examples!(Day05);
@@ -0,0 +1,11 @@
+use crate::Almanac;
+
+pub fn solve(almanac: &Almanac) -> usize {
+    let seed_to_location = almanac.seed_to_location();
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| seed_to_location.get(seed))
+        .min()
+        .expect("seeds, checked when parsing")
+}
@@ -0,0 +1,18 @@
+use crate::{Almanac, Range, RangeSet};
+
+/// Only the boundaries of the composed map matter, not how many seeds there
+/// are.
+pub fn solve(almanac: &Almanac) -> usize {
+    let starts = almanac.seeds.iter().step_by(2);
+    let lens = almanac.seeds.iter().skip(1).step_by(2);
+    let seed_ranges: RangeSet = starts
+        .zip(lens)
+        .map(|(&start, &len)| Range::new(start, len))
+        .collect();
+
+    almanac
+        .seed_to_location()
+        .translate_ranges(&seed_ranges)
+        .min()
+        .expect("non-empty seed ranges, checked when parsing")
+}
@@ -0,0 +1,15 @@
+extern crate day_06;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_06::Day06;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day06::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day06::part2(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,117 @@
+use common::{parse_number, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod race;
+
+pub use race::Race;
+
+pub struct Day06;
+
+/// The races of the sheet, read both ways: each column of the `Time:` and
+/// `Distance:` lines as a race of its own, and all of them as one race with
+/// the spaces between the digits removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sheet {
+    pub races: Vec<Race>,
+    pub joined: Race,
+}
+
+/// The numbers after `label` on `line`, one by one and joined.
+fn parse_numbers(line: &str, label: &str) -> Result<(Vec<u128>, u128), ParseError> {
+    let numbers = line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(line, line, format!("`{label}`")))?
+        .trim();
+    let each = numbers
+        .split_whitespace()
+        .map(|number| parse_number(line, number))
+        .collect::<Result<_, _>>()?;
+    let joined = numbers
+        .split_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new(line, numbers, "numbers that fit in 128 bits when joined"))?;
+    Ok((each, joined))
+}
+
+impl TryFrom<&str> for Sheet {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut lines = input.lines();
+        let mut numbers = |line_index: usize, label: &str| {
+            let line = lines
+                .next()
+                .ok_or_else(|| ParseError::missing(format!("a `{label}` line")))?;
+            parse_numbers(line, label)
+                .map(|numbers| (line, numbers))
+                .map_err(|err| err.offset(line_index))
+        };
+        let (_, (times, time)) = numbers(0, "Time:")?;
+        let (line, (records, record)) = numbers(1, "Distance:")?;
+        if records.len() != times.len() {
+            return Err(
+                ParseError::new(line, line, format!("{} distances", times.len())).offset(1),
+            );
+        }
+        Ok(Sheet {
+            races: times
+                .into_iter()
+                .zip(records)
+                .map(|(time, record)| Race::new(time, record))
+                .collect(),
+            joined: Race::new(time, record),
+        })
+    }
+}
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Sheet;
+    type Params = ();
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Sheet::try_from(input)
+    }
+
+    fn part1(sheet: &Self::Input, _params: &()) -> u128 {
+        part1::solve(sheet)
+    }
+
+    fn part2(sheet: &Self::Input, _params: &()) -> u128 {
+        part2::solve(sheet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_readings() {
+        let sheet = Day06::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+        assert_eq!(
+            sheet.races,
+            [Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+        );
+        assert_eq!(sheet.joined, Race::new(71530, 940200));
+    }
+
+    #[test]
+    fn bad_sheets() {
+        let err = Day06::parse("Time: 7 15\nDistance: 9\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        let err = Day06::parse("Time: 7 x\nDistance: 9 40\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (1, "x"));
+        let long = "9".repeat(30);
+        let err = Day06::parse(&format!("Time: {long} {long}\nDistance: 1 1\n")).unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!(Day06::parse(&format!("Time: {long}\nDistance: 1\n")).is_ok());
+    }
+}
+
+common::examples!(Day06);
@@ -0,0 +1,5 @@
+use crate::Sheet;
+
+pub fn solve(sheet: &Sheet) -> u128 {
+    sheet.races.iter().map(|race| race.ways_to_win()).product()
+}
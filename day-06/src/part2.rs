@@ -0,0 +1,5 @@
+use crate::Sheet;
+
+pub fn solve(sheet: &Sheet) -> u128 {
+    sheet.joined.ways_to_win()
+}
@@ -0,0 +1,160 @@
+/// A race of `time` milliseconds, in which holding the button for `h` of them
+/// makes the boat travel `h * (time - h)` millimetres.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u128,
+    pub record: u128,
+}
+
+impl Race {
+    pub fn new(time: u128, record: u128) -> Self {
+        Race { time, record }
+    }
+
+    /// Whether holding the button for `hold` beats the record, without ever
+    /// forming the distance: for positive `a`, `a * b > r` iff `b > r / a`.
+    pub fn is_winning(&self, hold: u128) -> bool {
+        hold > 0 && hold < self.time && self.time - hold > self.record / hold
+    }
+
+    /// The number of ways to hold the button that beat the record.
+    ///
+    /// Holding for `(time - n) / 2` or `(time + n) / 2` travels
+    /// `(time² - n²) / 4`, so the winning holds are those for the `n` of the
+    /// same parity as `time` with `n² < time² - 4 * record`, the largest of
+    /// which gives `n + 1` of them. The squares take up to 256 bits.
+    pub fn ways_to_win(&self) -> u128 {
+        let limit = mul(self.time, self.time);
+        let record = (self.record >> 126, self.record << 2);
+        if limit <= record {
+            return 0;
+        }
+        let largest = isqrt(sub(sub(limit, record), (0, 1)));
+        if largest % 2 == self.time % 2 {
+            largest + 1
+        } else {
+            largest
+        }
+    }
+}
+
+/// A 256-bit number as its high and low halves, which compare in that order.
+type Wide = (u128, u128);
+
+fn mul(a: u128, b: u128) -> Wide {
+    const LOW: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & LOW);
+    let (b_high, b_low) = (b >> 64, b & LOW);
+    let (middle, carry) = (a_high * b_low).overflowing_add(a_low * b_high);
+    let (low, carry_low) = (a_low * b_low).overflowing_add(middle << 64);
+    let high = a_high * b_high + (middle >> 64) + ((carry as u128) << 64) + carry_low as u128;
+    (high, low)
+}
+
+fn sub(a: Wide, b: Wide) -> Wide {
+    let (low, borrow) = a.1.overflowing_sub(b.1);
+    (a.0 - b.0 - borrow as u128, low)
+}
+
+/// The largest `root` with `root² <= n`, a bit at a time from the top.
+fn isqrt(n: Wide) -> u128 {
+    (0..128).rev().fold(0, |root, bit| {
+        let candidate = root | 1 << bit;
+        if mul(candidate, candidate) <= n {
+            candidate
+        } else {
+            root
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(7, 9, 4)]
+    #[case(15, 40, 8)]
+    #[case(30, 200, 9)]
+    #[case(71530, 940200, 71503)]
+    fn example_races(#[case] time: u128, #[case] record: u128, #[case] ways: u128) {
+        assert_eq!(Race::new(time, record).ways_to_win(), ways);
+    }
+
+    #[test]
+    fn matches_every_hold() {
+        for time in 0..60 {
+            for record in 0..time * time / 4 + 2 {
+                let race = Race::new(time, record);
+                let ways = (0..=time)
+                    .filter(|&hold| hold * (time - hold) > record)
+                    .count();
+                assert_eq!(race.ways_to_win(), ways as u128, "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn boundaries() {
+        // 10 * 20 ties the record, as does 20 * 10.
+        assert!(!Race::new(30, 200).is_winning(10));
+        assert!(Race::new(30, 200).is_winning(11));
+        // Only holding for half the time ties it.
+        assert_eq!(Race::new(8, 16).ways_to_win(), 0);
+        assert_eq!(Race::new(8, 15).ways_to_win(), 1);
+        assert_eq!(Race::new(9, 20).ways_to_win(), 0);
+        assert_eq!(Race::new(9, 19).ways_to_win(), 2);
+        assert_eq!(Race::new(0, 0).ways_to_win(), 0);
+        assert_eq!(Race::new(2, 0).ways_to_win(), 1);
+    }
+
+    #[test]
+    fn large_races() {
+        let time = 1 << 100;
+        assert_eq!(Race::new(time, 0).ways_to_win(), time - 1);
+        assert_eq!(Race::new(time, time - 1).ways_to_win(), time - 3);
+        // Just below 2^64, where the square of the time just fits in 128 bits.
+        let time = u64::MAX as u128;
+        let half = time / 2;
+        assert_eq!(Race::new(time, half * (time - half) - 1).ways_to_win(), 2);
+        // Just above, where only the middle hold wins.
+        let time = (1 << 64) + 2;
+        let half = time / 2;
+        assert_eq!(Race::new(time, half * half - 1).ways_to_win(), 1);
+        assert_eq!(Race::new(time, half * half).ways_to_win(), 0);
+        assert_eq!(Race::new(u128::MAX, u128::MAX).ways_to_win(), u128::MAX - 3);
+    }
+
+    #[test]
+    fn near_the_limit() {
+        let max = u128::MAX;
+        for time in [max, max - 1, max - 2, 1 << 127, (1 << 65) + 1] {
+            let half = time / 2;
+            for record in [0, 1, half, max / 3, max / 2, max - 1, max] {
+                let race = Race::new(time, record);
+                let ways = race.ways_to_win();
+                assert!(ways > 0 && ways < time, "{race:?}");
+                // The winning holds are the `ways` around the middle.
+                let first = (time - (ways - 1)) / 2;
+                let last = first + ways - 1;
+                assert!(race.is_winning(first) && race.is_winning(last), "{race:?}");
+                assert!(
+                    !race.is_winning(first - 1) && !race.is_winning(last + 1),
+                    "{race:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wide_arithmetic() {
+        let max = u128::MAX;
+        assert_eq!(mul(max, max), (max - 1, 1));
+        assert_eq!(mul(1 << 64, 1 << 64), (1, 0));
+        assert_eq!(sub((1, 0), (0, 1)), (0, max));
+        assert_eq!(isqrt((max - 1, 0)), max - 1);
+        assert_eq!(isqrt((max - 1, 1)), max);
+        assert_eq!(isqrt((0, 99)), 9);
+    }
+}
@@ -0,0 +1,15 @@
+extern crate day_07;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_07::Day07;
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day07::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day07::part2(black_box(&input), &()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
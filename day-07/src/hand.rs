@@ -0,0 +1,430 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::Play;
+
+/// A type of hand, given by the sizes of its groups of two or more equal
+/// cards, largest first: `[3, 2]` for a full house, `[]` for a high card.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandType {
+    pub name: String,
+    pub groups: Vec<u8>,
+}
+
+impl HandType {
+    pub fn new(name: &str, groups: &[u8]) -> Self {
+        HandType {
+            name: name.to_string(),
+            groups: groups.to_vec(),
+        }
+    }
+
+    /// Whether a hand with `groups`, largest first, has at least these.
+    fn fits(&self, groups: &[u8]) -> bool {
+        self.groups.len() <= groups.len()
+            && self
+                .groups
+                .iter()
+                .zip(groups)
+                .all(|(needed, had)| needed <= had)
+    }
+}
+
+/// How hands of Camel Cards are valued.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ruleset {
+    /// Cards from weakest to strongest, which also breaks ties between hands
+    /// of the same type.
+    pub order: Vec<char>,
+    /// Cards that stand in for whatever makes the strongest type. They keep
+    /// their own place in `order` when breaking ties.
+    pub wild: Vec<char>,
+    pub hand_size: usize,
+    /// From weakest to strongest. A hand is of the strongest type whose
+    /// groups it has.
+    pub types: Vec<HandType>,
+}
+
+impl Ruleset {
+    /// The rules of part 1.
+    pub fn standard() -> Self {
+        Ruleset {
+            order: "23456789TJQKA".chars().collect(),
+            wild: Vec::new(),
+            hand_size: 5,
+            types: vec![
+                HandType::new("high card", &[]),
+                HandType::new("one pair", &[2]),
+                HandType::new("two pair", &[2, 2]),
+                HandType::new("three of a kind", &[3]),
+                HandType::new("full house", &[3, 2]),
+                HandType::new("four of a kind", &[4]),
+                HandType::new("five of a kind", &[5]),
+            ],
+        }
+    }
+
+    /// The rules of part 2: `J` is the weakest card but wild.
+    pub fn jokers() -> Self {
+        Ruleset {
+            order: "J23456789TQKA".chars().collect(),
+            wild: vec!['J'],
+            ..Ruleset::standard()
+        }
+    }
+
+    /// `None` unless `cards` are `hand_size` cards out of `order` that fit
+    /// one of the types.
+    pub fn hand(&self, cards: &str) -> Option<Hand<'_>> {
+        let cards: Vec<char> = cards.chars().collect();
+        let strengths = cards
+            .iter()
+            .map(|&card| self.strength(card))
+            .collect::<Option<Vec<usize>>>()?;
+        if cards.len() != self.hand_size {
+            return None;
+        }
+
+        // Groups of equal cards that are not wild, the largest and strongest
+        // first.
+        let mut groups: Vec<(Option<char>, u8)> = Vec::new();
+        for &card in cards.iter().filter(|card| !self.wild.contains(card)) {
+            match groups.iter_mut().find(|(other, _)| *other == Some(card)) {
+                Some((_, count)) => *count += 1,
+                None => groups.push((Some(card), 1)),
+            }
+        }
+        groups.sort_by_key(|&(card, count)| (count, card.map(|card| self.strength(card))));
+        groups.reverse();
+
+        let wilds: Vec<usize> = (0..cards.len())
+            .filter(|&i| self.wild.contains(&cards[i]))
+            .collect();
+        let (r#type, targets) = self.best_type(&mut groups, wilds.len())?;
+        let substitutions = wilds
+            .into_iter()
+            .zip(targets)
+            .map(|(position, card)| Substitution {
+                position,
+                wild: cards[position],
+                card,
+            })
+            .collect();
+
+        Some(Hand {
+            rules: self,
+            cards,
+            strengths,
+            r#type,
+            substitutions,
+        })
+    }
+
+    fn strength(&self, card: char) -> Option<usize> {
+        self.order.iter().position(|&other| other == card)
+    }
+
+    /// The strongest type the `groups` can make with `wilds` more cards, and
+    /// which group each of them joins, `None` for one of its own. Joining the
+    /// largest group is tried first, so it wins among equally strong ways.
+    fn best_type(
+        &self,
+        groups: &mut Vec<(Option<char>, u8)>,
+        wilds: usize,
+    ) -> Option<(usize, Vec<Option<char>>)> {
+        if wilds == 0 {
+            let mut sizes: Vec<u8> = groups.iter().map(|&(_, count)| count).collect();
+            sizes.sort_by(|a, b| b.cmp(a));
+            sizes.retain(|&count| count > 1);
+            let r#type = self.types.iter().rposition(|r#type| r#type.fits(&sizes))?;
+            return Some((r#type, Vec::new()));
+        }
+
+        let mut best: Option<(usize, Vec<Option<char>>)> = None;
+        let mut consider = |found: Option<(usize, Vec<Option<char>>)>, card| {
+            if let Some((r#type, mut targets)) = found {
+                if best.as_ref().is_none_or(|(best, _)| r#type > *best) {
+                    targets.insert(0, card);
+                    best = Some((r#type, targets));
+                }
+            }
+        };
+        for i in 0..groups.len() {
+            // Joining one group or another of the same size is all the same.
+            if i > 0 && groups[i].1 == groups[i - 1].1 {
+                continue;
+            }
+            groups[i].1 += 1;
+            consider(self.best_type(groups, wilds - 1), groups[i].0);
+            groups[i].1 -= 1;
+        }
+        groups.push((None, 1));
+        consider(self.best_type(groups, wilds - 1), None);
+        groups.pop();
+        best
+    }
+
+    /// Ranks the hands of `plays` from weakest to strongest.
+    ///
+    /// Panics if one of them is not a hand under these rules.
+    pub fn rank(&self, plays: &[Play]) -> Vec<Ranked<'_>> {
+        let mut hands: Vec<(Hand, usize)> = plays
+            .iter()
+            .map(|play| {
+                let hand = self
+                    .hand(&play.cards)
+                    .unwrap_or_else(|| panic!("{} is not a hand under these rules", play.cards));
+                (hand, play.bid)
+            })
+            .collect();
+        hands.sort_by(|(hand, _), (other, _)| hand.cmp(other));
+
+        let mut ranked: Vec<Ranked> = Vec::with_capacity(hands.len());
+        for (rank, (hand, bid)) in (1..).zip(hands) {
+            let beats = ranked.last().map(|below| {
+                let reason = if hand.r#type > below.hand.r#type {
+                    Reason::Type
+                } else {
+                    match (0..hand.cards.len())
+                        .find(|&i| hand.strengths[i] != below.hand.strengths[i])
+                    {
+                        Some(position) => Reason::Card(position),
+                        None => Reason::Same,
+                    }
+                };
+                (below.hand.cards.iter().collect(), reason)
+            });
+            ranked.push(Ranked {
+                rank,
+                bid,
+                hand,
+                beats,
+            });
+        }
+        ranked
+    }
+
+    /// The sum of every bid times its rank.
+    pub fn winnings(&self, plays: &[Play]) -> usize {
+        self.rank(plays)
+            .iter()
+            .map(|ranked| ranked.rank * ranked.bid)
+            .sum()
+    }
+}
+
+/// A wild card and what it stands for, `None` for a card that is not
+/// otherwise in the hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    pub position: usize,
+    pub wild: char,
+    pub card: Option<char>,
+}
+
+/// A hand valued by a [`Ruleset`]. Hands compare by type first and then card
+/// by card.
+#[derive(Debug, Clone)]
+pub struct Hand<'a> {
+    rules: &'a Ruleset,
+    pub cards: Vec<char>,
+    /// Of each card, its place in the ruleset's order.
+    strengths: Vec<usize>,
+    /// Index into the ruleset's types.
+    r#type: usize,
+    pub substitutions: Vec<Substitution>,
+}
+
+impl Hand<'_> {
+    pub fn r#type(&self) -> &HandType {
+        &self.rules.types[self.r#type]
+    }
+}
+
+impl PartialEq for Hand<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Hand<'_> {}
+
+impl PartialOrd for Hand<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.r#type, &self.strengths).cmp(&(other.r#type, &other.strengths))
+    }
+}
+
+/// `T55J5: four of a kind, J as 5`
+impl fmt::Display for Hand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: String = self.cards.iter().collect();
+        write!(f, "{cards}: {}", self.r#type().name)?;
+        for substitution in &self.substitutions {
+            match substitution.card {
+                Some(card) => write!(f, ", {} as {card}", substitution.wild)?,
+                None => write!(f, ", {} as another card", substitution.wild)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Why a hand ranks above the one right below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// It is of a stronger type.
+    Type,
+    /// Same type, but the card at this position, counting from 0, is the
+    /// first one that is stronger.
+    Card(usize),
+    /// Both are equally strong, their order is that of the input.
+    Same,
+}
+
+/// A hand with its rank among all hands and why it got there.
+#[derive(Debug, Clone)]
+pub struct Ranked<'a> {
+    /// From 1 for the weakest hand.
+    pub rank: usize,
+    pub bid: usize,
+    pub hand: Hand<'a>,
+    /// The cards of the hand right below and why this one beats it, `None`
+    /// for the weakest.
+    pub beats: Option<(String, Reason)>,
+}
+
+/// `rank 4, KTJJT: four of a kind, J as T, J as T; beats QQQJA at card 1`
+impl fmt::Display for Ranked<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "rank {}, {}", self.rank, self.hand)?;
+        match &self.beats {
+            None => write!(f, "; the weakest"),
+            Some((below, Reason::Type)) => write!(f, "; beats {below} by type"),
+            Some((below, Reason::Card(position))) => {
+                write!(f, "; beats {below} at card {}", position + 1)
+            }
+            Some((below, Reason::Same)) => write!(f, "; as strong as {below}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_of(rules: &Ruleset, cards: &str) -> String {
+        rules.hand(cards).unwrap().r#type().name.clone()
+    }
+
+    #[test]
+    fn card_comp() {
+        let rules = Ruleset::standard();
+        assert!(rules.strength('T') > rules.strength('9'));
+        assert!(rules.strength('J') > rules.strength('T'));
+        assert!(Ruleset::jokers().strength('J') < Ruleset::jokers().strength('2'));
+    }
+
+    #[test]
+    fn hand_types() {
+        let rules = Ruleset::standard();
+        assert_eq!(type_of(&rules, "T55J5"), "three of a kind");
+        assert_eq!(type_of(&rules, "KTJJT"), "two pair");
+        assert_eq!(type_of(&rules, "23332"), "full house");
+        assert_eq!(type_of(&rules, "23456"), "high card");
+        assert!(rules.hand("2345").is_none());
+        assert!(rules.hand("2345X").is_none());
+    }
+
+    #[test]
+    fn jokers_are_wild() {
+        let rules = Ruleset::jokers();
+        assert_eq!(type_of(&rules, "T55J5"), "four of a kind");
+        assert_eq!(type_of(&rules, "TTTJ5"), "four of a kind");
+        assert_eq!(type_of(&rules, "2233J"), "full house");
+        assert_eq!(type_of(&rules, "JJJJJ"), "five of a kind");
+        assert_eq!(
+            rules.hand("KTJJT").unwrap().to_string(),
+            "KTJJT: four of a kind, J as T, J as T"
+        );
+        assert_eq!(
+            rules.hand("JJJJJ").unwrap().to_string(),
+            "JJJJJ: five of a kind, J as another card, J as another card, \
+             J as another card, J as another card, J as another card"
+        );
+    }
+
+    #[test]
+    fn compare_hands() {
+        let rules = Ruleset::standard();
+        let hand = |cards| rules.hand(cards).unwrap();
+        assert!(hand("T9876") > hand("954A2"));
+        assert!(hand("TT876") > hand("954A2"));
+        assert!(hand("TT876") > hand("95AA2"));
+        assert!(hand("33332") > hand("2AAAA"));
+        // Jokers make a stronger type but are the weakest at breaking ties.
+        let rules = Ruleset::jokers();
+        let hand = |cards| rules.hand(cards).unwrap();
+        assert!(hand("JKKK2") < hand("QQQQ2"));
+        assert!(hand("JKKK2") > hand("QQQ22"));
+    }
+
+    #[test]
+    fn custom_rules() {
+        // Three cards, deuces wild.
+        let rules = Ruleset {
+            order: "23456789TJQKA".chars().collect(),
+            wild: vec!['2'],
+            hand_size: 3,
+            types: vec![
+                HandType::new("nothing", &[]),
+                HandType::new("pair", &[2]),
+                HandType::new("triple", &[3]),
+            ],
+        };
+        assert_eq!(rules.hand("A2K").unwrap().to_string(), "A2K: pair, 2 as A");
+        assert_eq!(type_of(&rules, "AA2"), "triple");
+        assert_eq!(type_of(&rules, "222"), "triple");
+        assert!(rules.hand("AAAA").is_none());
+        assert_eq!(Play::parse("A2K 7", &rules).unwrap().cards, "A2K");
+        let err = Play::parse("A2KQ 7", &rules).unwrap_err();
+        assert_eq!(
+            (err.column, err.expected.as_str()),
+            (1, "3 cards out of `23456789TJQKA`")
+        );
+    }
+
+    #[test]
+    fn explain_ranks() {
+        let plays: Vec<Play> = [
+            "32T3K 765",
+            "T55J5 684",
+            "KK677 28",
+            "KTJJT 220",
+            "QQQJA 483",
+        ]
+        .into_iter()
+        .map(|line| Play::parse(line, &Ruleset::jokers()).unwrap())
+        .collect();
+        let explained: Vec<String> = Ruleset::jokers()
+            .rank(&plays)
+            .iter()
+            .map(|ranked| ranked.to_string())
+            .collect();
+        assert_eq!(
+            explained,
+            [
+                "rank 1, 32T3K: one pair; the weakest",
+                "rank 2, KK677: two pair; beats 32T3K by type",
+                "rank 3, T55J5: four of a kind, J as 5; beats KK677 by type",
+                "rank 4, QQQJA: four of a kind, J as Q; beats T55J5 at card 1",
+                "rank 5, KTJJT: four of a kind, J as T, J as T; beats QQQJA at card 1",
+            ]
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use common::{parse_lines, parse_number, split_once, ParseError, Solution};
+
+use crate::hand::Ruleset;
+
+pub mod hand;
+pub mod part1;
+pub mod part2;
+
+pub struct Day07;
+
+/// One line of the input, e.g. `32T3K 765`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Play {
+    pub cards: String,
+    pub bid: usize,
+}
+
+impl Play {
+    /// Reads a line with a hand of `rules.hand_size` cards out of the ones
+    /// `rules` orders.
+    pub fn parse(line: &str, rules: &Ruleset) -> Result<Self, ParseError> {
+        let (cards, bid) = split_once(line, line, " ")?;
+        if cards.chars().count() != rules.hand_size
+            || !cards.chars().all(|card| rules.order.contains(&card))
+        {
+            let order: String = rules.order.iter().collect();
+            let expected = format!("{} cards out of `{order}`", rules.hand_size);
+            return Err(ParseError::new(line, cards, expected));
+        }
+        Ok(Play {
+            cards: cards.to_string(),
+            bid: parse_number(line, bid)?,
+        })
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Play>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // Both parts play with the same cards and hand size.
+        parse_lines(input, |line| Play::parse(line, &Ruleset::standard()))
+    }
+
+    fn part1(plays: &Self::Input, _params: &()) -> usize {
+        part1::solve(plays)
+    }
+
+    fn part2(plays: &Self::Input, _params: &()) -> usize {
+        part2::solve(plays)
+    }
+}
+
+common::examples!(Day07);
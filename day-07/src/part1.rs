@@ -0,0 +1,5 @@
+use crate::{hand::Ruleset, Play};
+
+pub fn solve(plays: &[Play]) -> usize {
+    Ruleset::standard().winnings(plays)
+}
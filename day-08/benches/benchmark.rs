@@ -0,0 +1,15 @@
+extern crate day_08;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_08::{Day08, Params};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day08::parse(&normalize(include_str!("../res/examples/ghosts"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day08::part2(black_box(&input), &Params::default()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
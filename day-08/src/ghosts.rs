@@ -0,0 +1,369 @@
+use std::fmt;
+
+use crate::{Network, Nodes};
+
+/// Where a ghost's walk from one start node ends up repeating itself, and at
+/// which steps it is on an end node. A walk repeats once it is back on the
+/// same node at the same point of the instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the walk enters the cycle.
+    pub start: usize,
+    /// Steps of the cycle.
+    pub period: usize,
+    /// Steps before `start` that end on an end node.
+    pub early: Vec<usize>,
+    /// Steps within the first period from `start` on that end on an end node.
+    /// Each of them comes round again every `period` steps.
+    pub hits: Vec<usize>,
+}
+
+impl Cycle {
+    pub fn new(network: &Network, start: usize, is_end: impl Fn(usize) -> bool) -> Self {
+        let len = network.instructions.len();
+        // When each node was first left with each instruction.
+        let mut seen = vec![None; network.names.len() * len];
+        let mut ends = Vec::new();
+        let mut node = start;
+        for step in 0.. {
+            let at = step % len;
+            if let Some(first) = seen[node * len + at] {
+                let (early, hits) = ends.iter().partition(|&&end| end < first);
+                return Cycle {
+                    start: first,
+                    period: step - first,
+                    early,
+                    hits,
+                };
+            }
+            seen[node * len + at] = Some(step);
+            if is_end(node) {
+                ends.push(step);
+            }
+            node = network.step(node, at);
+        }
+        unreachable!("a walk over finitely many states repeats")
+    }
+
+    /// Whether the walk is on an end node after `step` steps.
+    pub fn is_hit(&self, step: usize) -> bool {
+        if step < self.start {
+            self.early.contains(&step)
+        } else {
+            self.hits
+                .contains(&(self.start + (step - self.start) % self.period))
+        }
+    }
+}
+
+/// The assumptions that make the answer the LCM of the periods, and whether
+/// every ghost's walk satisfies them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Assumptions {
+    /// There is one end node in each cycle, passed once per period.
+    pub one_hit_per_cycle: bool,
+    /// No end node is passed before the walk enters its cycle.
+    pub no_early_hits: bool,
+    /// The end node in the cycle is passed after exactly one period, so at
+    /// every multiple of it.
+    pub hit_at_period: bool,
+    /// Every period is a whole number of passes over the instructions.
+    pub whole_instructions: bool,
+}
+
+impl Assumptions {
+    pub fn of(cycles: &[Cycle], instructions: usize) -> Self {
+        Assumptions {
+            one_hit_per_cycle: cycles.iter().all(|cycle| cycle.hits.len() == 1),
+            no_early_hits: cycles.iter().all(|cycle| cycle.early.is_empty()),
+            hit_at_period: cycles
+                .iter()
+                .all(|cycle| cycle.hits.iter().all(|&hit| hit == cycle.period)),
+            whole_instructions: cycles.iter().all(|cycle| cycle.period % instructions == 0),
+        }
+    }
+
+    /// Whether the LCM of the periods is the answer.
+    pub fn lcm_applies(&self) -> bool {
+        self.one_hit_per_cycle && self.no_early_hits && self.hit_at_period
+    }
+}
+
+impl fmt::Display for Assumptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let yes_no = |holds| if holds { "yes" } else { "no" };
+        write!(
+            f,
+            "one end node per cycle: {}, ",
+            yes_no(self.one_hit_per_cycle)
+        )?;
+        write!(
+            f,
+            "no end node before the cycle: {}, ",
+            yes_no(self.no_early_hits)
+        )?;
+        write!(
+            f,
+            "end node after one period: {}, ",
+            yes_no(self.hit_at_period)
+        )?;
+        write!(
+            f,
+            "periods of whole instructions: {}",
+            yes_no(self.whole_instructions)
+        )
+    }
+}
+
+/// There is no step at which every walk from `start` is on `end` at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoWay {
+    pub start: Nodes,
+    pub end: Nodes,
+}
+
+impl fmt::Display for NoWay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no way from {} to {}", self.start, self.end)
+    }
+}
+
+/// The first step after which every walk is on an end node at once, `None`
+/// if that never happens.
+pub fn first_common_hit(cycles: &[Cycle], assumptions: &Assumptions) -> Option<usize> {
+    if assumptions.lcm_applies() {
+        return cycles
+            .iter()
+            .map(|cycle| cycle.period)
+            .try_fold(1, checked_lcm);
+    }
+
+    // Before every walk is in its cycle, just try each step.
+    let cycling = cycles.iter().map(|cycle| cycle.start).max().unwrap_or(0);
+    if let Some(step) = (1..cycling).find(|&step| cycles.iter().all(|cycle| cycle.is_hit(step))) {
+        return Some(step);
+    }
+
+    if cycles.iter().any(|cycle| cycle.hits.is_empty()) {
+        return None;
+    }
+    // After that, every choice of a hit per cycle gives a system of
+    // congruences, whose smallest solution from `cycling` on is a candidate.
+    let mut best: Option<u128> = None;
+    let mut choice = vec![0; cycles.len()];
+    'choices: loop {
+        let solution =
+            cycles
+                .iter()
+                .zip(&choice)
+                .try_fold((0, 1), |(residue, modulus), (cycle, &i)| {
+                    crt(
+                        residue,
+                        modulus,
+                        cycle.hits[i] as u128,
+                        cycle.period as u128,
+                    )
+                });
+        if let Some((residue, modulus)) = solution {
+            let from = cycling.max(1) as u128;
+            let step = if residue >= from {
+                residue
+            } else {
+                residue + (from - residue).div_ceil(modulus) * modulus
+            };
+            best = Some(best.map_or(step, |best| best.min(step)));
+        }
+
+        // The next choice, counting with one digit per cycle.
+        for (i, cycle) in cycles.iter().enumerate() {
+            choice[i] += 1;
+            if choice[i] < cycle.hits.len() {
+                continue 'choices;
+            }
+            choice[i] = 0;
+        }
+        break;
+    }
+    best.and_then(|step| step.try_into().ok())
+}
+
+/// Walks from every node `starts` picks at once. Returns the first step at
+/// which all walks are on nodes `ends` picks, if they ever are, and what their
+/// cycles look like.
+pub fn walk(network: &Network, starts: &Nodes, ends: &Nodes) -> (Option<usize>, Assumptions) {
+    let is_end: Vec<bool> = network
+        .names
+        .iter()
+        .map(|name| ends.matches(name))
+        .collect();
+    let cycles: Vec<Cycle> = network
+        .select(starts)
+        .into_iter()
+        .map(|start| Cycle::new(network, start, |node| is_end[node]))
+        .collect();
+    let assumptions = Assumptions::of(&cycles, network.instructions.len());
+    if cycles.is_empty() {
+        return (None, assumptions);
+    }
+    (first_common_hit(&cycles, &assumptions), assumptions)
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+    (a / gcd(a as u128, b as u128) as usize).checked_mul(b)
+}
+
+/// Solves `x = a (mod m)` and `x = b (mod n)` for moduli that need not be
+/// coprime, as `x = residue (mod modulus)`. `None` if there is no solution.
+fn crt(a: u128, m: u128, b: u128, n: u128) -> Option<(u128, u128)> {
+    let (a, b) = (a % m, b % n);
+    let g = gcd(m, n);
+    let diff = (b as i128 - a as i128).rem_euclid(n as i128) as u128;
+    if !diff.is_multiple_of(g) {
+        return None;
+    }
+    let modulus = m / g * n;
+    // m * k = diff (mod n), so k = diff / g * inverse(m / g) (mod n / g).
+    let reduced = n / g;
+    let k = if reduced == 1 {
+        0
+    } else {
+        diff / g % reduced * inverse(m / g % reduced, reduced) % reduced
+    };
+    Some(((a + m * k) % modulus, modulus))
+}
+
+/// The inverse of `a` modulo `m`, for coprime `a` and `m`.
+fn inverse(a: u128, m: u128) -> u128 {
+    let (mut old_r, mut r) = (a as i128, m as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    old_s.rem_euclid(m as i128) as u128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    use crate::Day08;
+
+    fn ghosts() -> Network {
+        Day08::parse(&common::normalize(include_str!("../res/examples/ghosts"))).unwrap()
+    }
+
+    #[test]
+    fn cycles_of_ghosts() {
+        let network = ghosts();
+        let is_end = |node| network.name(node).ends_with('Z');
+        let id = |name| network.id(name).unwrap();
+        assert_eq!(
+            Cycle::new(&network, id("11A"), is_end),
+            Cycle {
+                start: 1,
+                period: 2,
+                early: vec![],
+                hits: vec![2],
+            }
+        );
+        let cycle = Cycle::new(&network, id("22A"), is_end);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 1,
+                period: 6,
+                early: vec![],
+                hits: vec![3, 6],
+            }
+        );
+        assert!(cycle.is_hit(9) && cycle.is_hit(12) && !cycle.is_hit(10));
+    }
+
+    #[test]
+    fn assumptions_of_ghosts() {
+        let network = ghosts();
+        let (steps, assumptions) = walk(
+            &network,
+            &Nodes::Suffix("A".into()),
+            &Nodes::Suffix("Z".into()),
+        );
+        assert!(!assumptions.one_hit_per_cycle && !assumptions.lcm_applies());
+        assert!(assumptions.whole_instructions);
+        assert_eq!(steps, Some(6));
+        // Any pair of nodes.
+        let (steps, _) = walk(
+            &network,
+            &Nodes::Named("22B".into()),
+            &Nodes::Named("22Z".into()),
+        );
+        assert_eq!(steps, Some(2));
+        let (steps, _) = walk(
+            &network,
+            &Nodes::Named("11A".into()),
+            &Nodes::Named("22Z".into()),
+        );
+        assert_eq!(steps, None);
+        let (steps, _) = walk(
+            &network,
+            &Nodes::Named("QQQ".into()),
+            &Nodes::Named("22Z".into()),
+        );
+        assert_eq!(steps, None);
+    }
+
+    fn cycle(start: usize, period: usize, early: &[usize], hits: &[usize]) -> Cycle {
+        Cycle {
+            start,
+            period,
+            early: early.to_vec(),
+            hits: hits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn common_hits() {
+        let solve = |cycles: &[Cycle]| first_common_hit(cycles, &Assumptions::of(cycles, 1));
+        assert_eq!(
+            solve(&[cycle(2, 4, &[], &[4]), cycle(3, 6, &[], &[6])]),
+            Some(12)
+        );
+        // Odd and even steps never meet.
+        assert_eq!(
+            solve(&[cycle(0, 4, &[], &[3]), cycle(0, 6, &[], &[4])]),
+            None
+        );
+        assert_eq!(
+            solve(&[cycle(0, 4, &[], &[3]), cycle(0, 6, &[], &[1, 5])]),
+            Some(7)
+        );
+        // Both pass an end node early, then never together.
+        assert_eq!(
+            solve(&[cycle(5, 2, &[3], &[5]), cycle(5, 2, &[3], &[6])]),
+            Some(3)
+        );
+        assert_eq!(
+            solve(&[cycle(5, 2, &[], &[5]), cycle(0, 5, &[], &[])]),
+            None
+        );
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        assert_eq!(crt(2, 3, 3, 5), Some((8, 15)));
+        assert_eq!(crt(3, 4, 5, 6), Some((11, 12)));
+        assert_eq!(crt(3, 4, 2, 6), None);
+        assert_eq!(crt(1, 4, 3, 6), Some((9, 12)));
+        assert_eq!(crt(0, 1, 4, 7), Some((4, 7)));
+    }
+}
@@ -0,0 +1,258 @@
+use std::{collections::HashMap, fmt, str::FromStr};
+
+use common::{
+    blocks, check_chars, parse_lines, parse_param, split_once, ParamError, ParseError, Solution,
+};
+
+pub mod ghosts;
+pub mod part1;
+pub mod part2;
+
+pub struct Day08;
+
+/// The nodes, numbered in the order they are defined, and where each
+/// instruction leads from them.
+#[derive(Debug)]
+pub struct Network {
+    pub instructions: String,
+    /// Node names by id.
+    pub names: Vec<String>,
+    /// Left and right successor of each node by id.
+    pub next: Vec<[usize; 2]>,
+    ids: HashMap<String, usize>,
+}
+
+impl Network {
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    /// Where the instruction at `at` leads from `node`.
+    pub fn step(&self, node: usize, at: usize) -> usize {
+        let turn = self.instructions.as_bytes()[at];
+        self.next[node][usize::from(turn == b'R')]
+    }
+
+    /// The ids of the nodes that `nodes` picks, in order.
+    pub fn select(&self, nodes: &Nodes) -> Vec<usize> {
+        (0..self.names.len())
+            .filter(|&id| nodes.matches(&self.names[id]))
+            .collect()
+    }
+}
+
+fn parse_node(line: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let (from, to) = split_once(line, line, " = ")?;
+    let to = to
+        .strip_prefix('(')
+        .and_then(|to| to.strip_suffix(')'))
+        .ok_or_else(|| ParseError::new(line, to, "`(<left>, <right>)`"))?;
+    let (left, right) = split_once(line, to, ", ")?;
+    Ok((from, (left, right)))
+}
+
+impl TryFrom<&str> for Network {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut blocks = blocks(input);
+        let missing = || ParseError::missing("instructions and nodes separated by a blank line");
+        let (first, instructions) = blocks.next().ok_or_else(missing)?;
+        let (offset, nodes) = blocks.next().ok_or_else(missing)?;
+
+        check_chars(
+            instructions,
+            instructions,
+            |c| c == 'L' || c == 'R',
+            "`L` or `R`",
+        )
+        .map_err(|err| err.offset(first))?;
+
+        let lines: Vec<&str> = nodes.lines().collect();
+        let nodes = parse_lines(nodes, parse_node).map_err(|err| err.offset(offset))?;
+
+        let mut ids = HashMap::new();
+        for (i, &(name, _)) in nodes.iter().enumerate() {
+            if ids.insert(name.to_string(), i).is_some() {
+                return Err(
+                    ParseError::new(lines[i], name, "a node not defined before").offset(offset + i)
+                );
+            }
+        }
+        let next = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, &(_, (left, right)))| {
+                let id = |name: &str| {
+                    ids.get(name).copied().ok_or_else(|| {
+                        ParseError::new(lines[i], name, "a defined node").offset(offset + i)
+                    })
+                };
+                Ok([id(left)?, id(right)?])
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        Ok(Network {
+            instructions: instructions.to_string(),
+            names: nodes.iter().map(|&(name, _)| name.to_string()).collect(),
+            next,
+            ids,
+        })
+    }
+}
+
+/// Picks nodes by name: `AAA` for one node, `*Z` for every node ending in
+/// `Z` and `AAA,BBB` for a list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Nodes {
+    Named(String),
+    Suffix(String),
+    List(Vec<String>),
+}
+
+impl Nodes {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Nodes::Named(node) => name == node,
+            Nodes::Suffix(suffix) => name.ends_with(suffix.as_str()),
+            Nodes::List(nodes) => nodes.iter().any(|node| node == name),
+        }
+    }
+}
+
+impl FromStr for Nodes {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(suffix) = s.strip_prefix('*') {
+            Ok(Nodes::Suffix(suffix.to_string()))
+        } else if s.contains(',') {
+            Ok(Nodes::List(s.split(',').map(str::to_string).collect()))
+        } else if !s.is_empty() {
+            Ok(Nodes::Named(s.to_string()))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl fmt::Display for Nodes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Nodes::Named(node) => write!(f, "{node}"),
+            Nodes::Suffix(suffix) => write!(f, "*{suffix}"),
+            Nodes::List(nodes) => write!(f, "{}", nodes.join(",")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Where part 1 walks from and to.
+    pub start: Nodes,
+    pub end: Nodes,
+    /// Where the ghosts of part 2 walk from and to.
+    pub ghost_start: Nodes,
+    pub ghost_end: Nodes,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            start: Nodes::Named("AAA".to_string()),
+            end: Nodes::Named("ZZZ".to_string()),
+            ghost_start: Nodes::Suffix("A".to_string()),
+            ghost_end: Nodes::Suffix("Z".to_string()),
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "start" => self.start = parse_param(key, value)?,
+            "end" => self.end = parse_param(key, value)?,
+            "ghost_start" => self.ghost_start = parse_param(key, value)?,
+            "ghost_end" => self.ghost_end = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Network;
+    type Params = Params;
+    type Answer1 = Result<usize, ghosts::NoWay>;
+    type Answer2 = Result<usize, ghosts::NoWay>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Network::try_from(input)
+    }
+
+    fn part1(network: &Self::Input, params: &Params) -> Result<usize, ghosts::NoWay> {
+        part1::solve(network, &params.start, &params.end)
+    }
+
+    fn part2(network: &Self::Input, params: &Params) -> Result<usize, ghosts::NoWay> {
+        part2::solve(network, &params.ghost_start, &params.ghost_end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interned_nodes() {
+        let network =
+            Day08::parse("LR\n\nAAA = (BBB, CCC)\nBBB = (AAA, BBB)\nCCC = (CCC, CCC)\n").unwrap();
+        assert_eq!(network.id("CCC"), Some(2));
+        assert_eq!(network.name(1), "BBB");
+        assert_eq!(network.next, [[1, 2], [0, 1], [2, 2]]);
+        assert_eq!(network.step(0, 1), 2);
+
+        let err = Day08::parse("LR\n\nAAA = (BBB, DDD)\nBBB = (AAA, BBB)\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 13, "DDD"));
+        let err = Day08::parse("LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").unwrap_err();
+        assert_eq!(err.line, 4);
+    }
+
+    #[test]
+    fn select_nodes() {
+        let network =
+            Day08::parse("L\n\n11A = (11Z, 22A)\n22A = (11Z, 11Z)\n11Z = (11Z, 11Z)\n").unwrap();
+        let select = |nodes: &str| network.select(&nodes.parse().unwrap());
+        assert_eq!(select("*A"), [0, 1]);
+        assert_eq!(select("11Z"), [2]);
+        assert_eq!(select("11Z,22A"), [1, 2]);
+        assert_eq!(select("*Q"), []);
+        assert!("".parse::<Nodes>().is_err());
+        assert_eq!(Nodes::List(vec!["A".into(), "B".into()]).to_string(), "A,B");
+    }
+
+    #[test]
+    fn no_way() {
+        let network =
+            Day08::parse("L\n\n11A = (11B, 11B)\n11B = (11B, 11B)\n22Z = (22Z, 22Z)\n").unwrap();
+        let params = Params {
+            start: Nodes::Named("11A".to_string()),
+            end: Nodes::Named("22Z".to_string()),
+            ..Params::default()
+        };
+        assert_eq!(
+            Day08::part1(&network, &params).unwrap_err().to_string(),
+            "no way from 11A to 22Z"
+        );
+        assert!(Day08::part1(&network, &Params::default()).is_err());
+        assert!(Day08::part2(&network, &Params::default()).is_err());
+    }
+}
+
+common::examples!(Day08);
@@ -0,0 +1,11 @@
+use crate::{
+    ghosts::{walk, NoWay},
+    Network, Nodes,
+};
+
+pub fn solve(network: &Network, start: &Nodes, end: &Nodes) -> Result<usize, NoWay> {
+    walk(network, start, end).0.ok_or_else(|| NoWay {
+        start: start.clone(),
+        end: end.clone(),
+    })
+}
@@ -0,0 +1,22 @@
+use tracing::{info, warn};
+
+use crate::{
+    ghosts::{walk, NoWay},
+    Network, Nodes,
+};
+
+pub fn solve(network: &Network, start: &Nodes, end: &Nodes) -> Result<usize, NoWay> {
+    let (steps, assumptions) = walk(network, start, end);
+    // The runner only shows warnings by default, so the assumptions are only
+    // reported unasked when the shortcut does not hold.
+    if assumptions.lcm_applies() {
+        info!("{assumptions}");
+    } else {
+        warn!("{assumptions}");
+        warn!("solving the general case with the chinese remainder theorem");
+    }
+    steps.ok_or_else(|| NoWay {
+        start: start.clone(),
+        end: end.clone(),
+    })
+}
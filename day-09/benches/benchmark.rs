@@ -0,0 +1,15 @@
+extern crate day_09;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_09::{Day09, Params};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day09::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day09::part2(black_box(&input), &Params::default()))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
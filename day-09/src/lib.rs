@@ -0,0 +1,108 @@
+use common::{parse_lines, parse_number, parse_param, ParamError, ParseError, Solution};
+use tracing::warn;
+
+use crate::sequence::{Sequence, SequenceError};
+
+pub mod part1;
+pub mod part2;
+pub mod sequence;
+
+pub struct Day09;
+
+pub struct Params {
+    /// How far to extrapolate, after the history in part 1 and before it in
+    /// part 2.
+    pub steps: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { steps: 1 }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "steps" => self.steps = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+/// Sums `extrapolate` over the sequences of all histories. Histories whose
+/// differences never reach zero are reported, as their polynomial is only one
+/// of many that fit.
+pub fn sum_extrapolated(
+    histories: &[Vec<i128>],
+    extrapolate: impl Fn(&Sequence) -> Result<i128, SequenceError>,
+) -> Result<i128, SequenceError> {
+    histories
+        .iter()
+        .zip(1..)
+        .try_fold(0i128, |sum, (history, line)| {
+            let value = Sequence::new(history)
+                .and_then(|sequence| {
+                    if !sequence.converges() {
+                        warn!("history on line {line} never reaches all-zero differences");
+                    }
+                    extrapolate(&sequence)
+                })
+                .map_err(|err| SequenceError::Line(line, Box::new(err)))?;
+            sum.checked_add(value).ok_or(SequenceError::SumOverflow)
+        })
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    /// One value history per line of the OASIS report.
+    type Input = Vec<Vec<i128>>;
+    type Params = Params;
+    type Answer1 = Result<i128, SequenceError>;
+    type Answer2 = Result<i128, SequenceError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, |line| {
+            line.split_whitespace()
+                .map(|number| parse_number(line, number))
+                .collect()
+        })
+    }
+
+    fn part1(histories: &Self::Input, params: &Params) -> Result<i128, SequenceError> {
+        part1::solve(histories, params.steps)
+    }
+
+    fn part2(histories: &Self::Input, params: &Params) -> Result<i128, SequenceError> {
+        part2::solve(histories, params.steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_name_the_line() {
+        let params = Params::default();
+        let histories = Day09::parse("1 2 3\n\n").unwrap();
+        let err = Day09::part1(&histories, &params).unwrap_err();
+        assert_eq!(err, SequenceError::Line(2, Box::new(SequenceError::Empty)));
+        assert_eq!(err.to_string(), "history on line 2: empty history");
+
+        let histories = vec![vec![i128::MAX], vec![1]];
+        assert_eq!(
+            Day09::part1(&histories, &params),
+            Err(SequenceError::SumOverflow)
+        );
+        let histories = vec![vec![0], vec![i128::MAX, 0]];
+        assert_eq!(
+            Day09::part2(&histories, &params),
+            Err(SequenceError::Line(2, Box::new(SequenceError::Overflow)))
+        );
+    }
+}
+
+common::examples!(Day09);
@@ -0,0 +1,5 @@
+use crate::{sequence::SequenceError, sum_extrapolated};
+
+pub fn solve(histories: &[Vec<i128>], steps: u64) -> Result<i128, SequenceError> {
+    sum_extrapolated(histories, |sequence| sequence.backward(steps))
+}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+/// Why a history could not be extrapolated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SequenceError {
+    /// A history without values.
+    Empty,
+    /// A value or difference does not fit in an `i128`.
+    Overflow,
+    /// The sum over all histories does not fit in an `i128`.
+    SumOverflow,
+    /// The history on a line of the report could not be extrapolated.
+    Line(usize, Box<SequenceError>),
+}
+
+impl fmt::Display for SequenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SequenceError::Empty => write!(f, "empty history"),
+            SequenceError::Overflow => write!(f, "overflows i128"),
+            SequenceError::SumOverflow => write!(f, "the sum overflows i128"),
+            SequenceError::Line(line, err) => write!(f, "history on line {line}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for SequenceError {}
+
+/// A value history as the polynomial of lowest degree through all its values,
+/// kept as the forward differences at its first value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence {
+    len: usize,
+    /// The first value, the first of its differences, the first of their
+    /// differences and so on, up to the last one that is not zero.
+    differences: Vec<i128>,
+    /// Whether the differences became all zero within the history.
+    converges: bool,
+}
+
+impl Sequence {
+    pub fn new(history: &[i128]) -> Result<Self, SequenceError> {
+        if history.is_empty() {
+            return Err(SequenceError::Empty);
+        }
+        let mut row = history.to_vec();
+        let mut differences = Vec::new();
+        while !row.is_empty() && row.iter().any(|&value| value != 0) {
+            differences.push(row[0]);
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]))
+                .collect::<Option<_>>()
+                .ok_or(SequenceError::Overflow)?;
+        }
+        Ok(Sequence {
+            len: history.len(),
+            differences,
+            converges: !row.is_empty(),
+        })
+    }
+
+    /// The degree of the polynomial, `None` for a history of zeros.
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// Whether the history has more values than its polynomial needs, so
+    /// that its differences reach a row of zeros. If they do not, any next
+    /// value would fit and the one of the polynomial is just a guess.
+    pub fn converges(&self) -> bool {
+        self.converges
+    }
+
+    /// The value at `position`, counting from 0 for the first value of the
+    /// history. Negative positions lie before it.
+    pub fn at(&self, position: i128) -> Result<i128, SequenceError> {
+        // Newton's forward formula, with the binomial coefficient of
+        // `position` over `i` for each difference.
+        let mut value: i128 = 0;
+        let mut binomial: i128 = 1;
+        for (i, &difference) in (0..).zip(&self.differences) {
+            if i > 0 {
+                // A product of `i` consecutive numbers is divisible by `i!`,
+                // so this stays exact.
+                binomial = binomial
+                    .checked_mul(position - (i - 1))
+                    .ok_or(SequenceError::Overflow)?
+                    / i;
+            }
+            value = binomial
+                .checked_mul(difference)
+                .and_then(|term| value.checked_add(term))
+                .ok_or(SequenceError::Overflow)?;
+        }
+        Ok(value)
+    }
+
+    /// The value `steps` after the last one of the history.
+    pub fn forward(&self, steps: u64) -> Result<i128, SequenceError> {
+        self.at(self.len as i128 - 1 + i128::from(steps))
+    }
+
+    /// The value `steps` before the first one of the history.
+    pub fn backward(&self, steps: u64) -> Result<i128, SequenceError> {
+        self.at(-i128::from(steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linear() {
+        let sequence = Sequence::new(&[0, 3, 6, 9, 12, 15]).unwrap();
+        assert_eq!(sequence.degree(), Some(1));
+        assert!(sequence.converges());
+        assert_eq!(sequence.forward(1), Ok(18));
+        assert_eq!(sequence.forward(10), Ok(45));
+        assert_eq!(sequence.backward(1), Ok(-3));
+        assert_eq!(sequence.at(2), Ok(6));
+    }
+
+    #[test]
+    fn cubic() {
+        let sequence = Sequence::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(sequence.degree(), Some(3));
+        assert_eq!(sequence.forward(1), Ok(68));
+        assert_eq!(sequence.forward(2), Ok(101));
+        assert_eq!(sequence.backward(1), Ok(5));
+        assert_eq!(sequence.backward(2), Ok(-4));
+    }
+
+    #[test]
+    fn zeros() {
+        let sequence = Sequence::new(&[0, 0, 0]).unwrap();
+        assert_eq!(sequence.degree(), None);
+        assert_eq!(sequence.forward(5), Ok(0));
+        assert_eq!(Sequence::new(&[]), Err(SequenceError::Empty));
+    }
+
+    #[test]
+    fn not_converging() {
+        // 1, 2, 4 doubles, but the parabola through it goes on with 7.
+        let sequence = Sequence::new(&[1, 2, 4]).unwrap();
+        assert!(!sequence.converges());
+        assert_eq!(sequence.forward(1), Ok(7));
+        assert!(!Sequence::new(&[5]).unwrap().converges());
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(
+            Sequence::new(&[i128::MIN, i128::MAX]),
+            Err(SequenceError::Overflow)
+        );
+        let sequence = Sequence::new(&[0, i128::MAX / 2]).unwrap();
+        assert_eq!(sequence.forward(1), Ok(i128::MAX / 2 * 2));
+        assert_eq!(sequence.forward(2), Err(SequenceError::Overflow));
+    }
+}
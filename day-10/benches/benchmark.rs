@@ -0,0 +1,21 @@
+extern crate day_10;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_10::{Day10, Method, Params};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day10::parse(&normalize(include_str!("../res/examples/larger"))).unwrap();
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day10::part2(black_box(&input), &Params::default()))
+    });
+    let flood = Params {
+        method: Method::Flood,
+    };
+    c.bench_function("solver part2 flood", |b| {
+        b.iter(|| Day10::part2(black_box(&input), &flood))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
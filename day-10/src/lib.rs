@@ -0,0 +1,109 @@
+use std::str::FromStr;
+
+use common::{parse_param, ParamError, ParseError, Solution};
+use grid::{Grid, Point};
+
+pub mod loops;
+pub mod part1;
+pub mod part2;
+pub mod regions;
+
+pub struct Day10;
+
+/// The pipe field as it was read.
+#[derive(Debug, Clone)]
+pub struct Map {
+    pub field: Grid<char>,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // The puzzle examples mark tiles inside and outside the loop with
+        // `I` and `O`, both are plain ground.
+        let field = Grid::parse_with(input, |c| match c {
+            '|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' | 'I' | 'O' => Ok(c),
+            _ => Err(c),
+        })?;
+        let Some((y, x)) = field.position(|&c| c == 'S') else {
+            return Err(ParseError::missing("a start tile `S`"));
+        };
+        let map = Map { field };
+        if loops::main_loop(&map).is_none() {
+            let line = input.lines().nth(y).unwrap_or_default();
+            let start = line.get(x..x + 1).unwrap_or(line);
+            return Err(ParseError::new(line, start, "a start tile on a loop of pipes").offset(y));
+        }
+        Ok(map)
+    }
+}
+
+impl Map {
+    pub fn start(&self) -> Point<usize> {
+        let (y, x) = self.field.position(|&c| c == 'S').unwrap();
+        Point::new(x, y)
+    }
+}
+
+/// How part 2 tells the tiles inside the loop from the ones outside.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Method {
+    /// Crossing the loop along each row, counting how often it is crossed.
+    #[default]
+    Scan,
+    /// Filling in from the outside on the map at three times its size,
+    /// squeezing between pipes.
+    Flood,
+}
+
+impl FromStr for Method {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "scan" => Ok(Method::Scan),
+            "flood" => Ok(Method::Flood),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    /// How part 2 finds the tiles inside the loop.
+    pub method: Method,
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "method" => self.method = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Map;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::try_from(input)
+    }
+
+    fn part1(map: &Self::Input, _params: &Params) -> usize {
+        part1::solve(map)
+    }
+
+    fn part2(map: &Self::Input, params: &Params) -> usize {
+        part2::solve(map, params.method)
+    }
+}
+
+common::examples!(Day10);
@@ -0,0 +1,221 @@
+use grid::{geometry, Direction, Grid, Point};
+
+use crate::Map;
+
+/// The two directions a pipe opens to, `None` for anything that is not a
+/// pipe.
+pub fn openings(pipe: char) -> Option<[Direction; 2]> {
+    use Direction::*;
+    match pipe {
+        '|' => Some([Up, Down]),
+        '-' => Some([Right, Left]),
+        'L' => Some([Up, Right]),
+        'J' => Some([Up, Left]),
+        '7' => Some([Down, Left]),
+        'F' => Some([Right, Down]),
+        _ => None,
+    }
+}
+
+/// The pipe that opens to `a` and `b`, `None` if they are the same.
+fn pipe_between(a: Direction, b: Direction) -> Option<char> {
+    "|-LJ7F".chars().find(|&pipe| {
+        openings(pipe).is_some_and(|openings| openings.contains(&a) && openings.contains(&b))
+            && a != b
+    })
+}
+
+/// A closed loop of pipes, as its tiles in the order it passes them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub tiles: Vec<Point<usize>>,
+}
+
+impl Loop {
+    /// Follows the pipes from `start`, read as `pipe`, until they lead back
+    /// to it. `None` if they lead off the map or into a tile they do not
+    /// connect to.
+    pub fn trace(map: &Map, start: Point<usize>, pipe: char) -> Option<Self> {
+        match follow(map, start, pipe) {
+            (tiles, true) => Some(Loop { tiles }),
+            (_, false) => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Steps from the first tile to the one farthest along the loop.
+    pub fn farthest(&self) -> usize {
+        self.len() / 2
+    }
+
+    /// The number of tiles the loop encloses, from its area by the shoelace
+    /// formula and Pick's theorem, with the tile centres as lattice points.
+    pub fn enclosed(&self) -> usize {
+        let vertices: Vec<Point<isize>> = self
+            .tiles
+            .iter()
+            .map(|tile| Point::new(tile.x as isize, tile.y as isize))
+            .collect();
+        geometry::interior_points(&vertices) as usize
+    }
+}
+
+/// The tiles passed following the pipes from `start`, read as `pipe`, and
+/// whether they lead back to it.
+fn follow(map: &Map, start: Point<usize>, pipe: char) -> (Vec<Point<usize>>, bool) {
+    let mut tiles = vec![start];
+    let Some([mut direction, back]) = openings(pipe) else {
+        return (tiles, false);
+    };
+    let mut pos = start;
+    loop {
+        let Some(next) = map.field.step(pos, direction) else {
+            return (tiles, false);
+        };
+        if next == start {
+            return (tiles, direction.opposite() == back);
+        }
+        let came_from = direction.opposite();
+        direction = match openings(map.field[next]) {
+            Some([a, b]) if a == came_from => b,
+            Some([a, b]) if b == came_from => a,
+            _ => return (tiles, false),
+        };
+        tiles.push(next);
+        pos = next;
+    }
+}
+
+/// The pipe hidden under the start tile: one that opens to two neighbours
+/// connecting back to it, and closes a loop through them. Where more than
+/// two neighbours connect, the first such pair that closes a loop wins.
+pub fn start_pipe(map: &Map) -> Option<char> {
+    let start = map.start();
+    let connected: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|&direction| {
+            map.field.step(start, direction).is_some_and(|next| {
+                openings(map.field[next])
+                    .is_some_and(|openings| openings.contains(&direction.opposite()))
+            })
+        })
+        .collect();
+    connected
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &a)| connected[i + 1..].iter().map(move |&b| (a, b)))
+        .filter_map(|(a, b)| pipe_between(a, b))
+        .find(|&pipe| Loop::trace(map, start, pipe).is_some())
+}
+
+/// The loop through the start tile and the pipe under it.
+pub fn main_loop(map: &Map) -> Option<(char, Loop)> {
+    let pipe = start_pipe(map)?;
+    let tiles = Loop::trace(map, map.start(), pipe)?;
+    Some((pipe, tiles))
+}
+
+/// Every closed loop of pipes apart from `main`, each starting at its top
+/// left tile.
+pub fn other_loops(map: &Map, main: &Loop) -> Vec<Loop> {
+    let mut seen = Grid::init(map.field.rows(), map.field.cols(), false);
+    for &tile in &main.tiles {
+        seen[tile] = true;
+    }
+    let mut loops = Vec::new();
+    for y in 0..map.field.rows() {
+        for x in 0..map.field.cols() {
+            // The top left tile of a loop can only be an `F`.
+            let tile = Point::new(x, y);
+            if seen[tile] || map.field[tile] != 'F' {
+                continue;
+            }
+            // Tiles passed on the way are either on this loop or on a
+            // chain of pipes that goes nowhere, so need no second look.
+            let (tiles, closed) = follow(map, tile, 'F');
+            for &tile in &tiles {
+                seen[tile] = true;
+            }
+            if closed {
+                loops.push(Loop { tiles });
+            }
+        }
+    }
+    loops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{normalize, Solution};
+
+    use crate::Day10;
+
+    fn example(name: &str) -> Map {
+        let input = match name {
+            "square" => include_str!("../res/examples/square"),
+            "complex" => include_str!("../res/examples/complex"),
+            "larger" => include_str!("../res/examples/larger"),
+            _ => unreachable!(),
+        };
+        Day10::parse(&normalize(input)).unwrap()
+    }
+
+    #[test]
+    fn pipes() {
+        for pipe in "|-LJ7F".chars() {
+            let [a, b] = openings(pipe).unwrap();
+            assert_eq!(pipe_between(a, b), Some(pipe));
+            assert_eq!(pipe_between(b, a), Some(pipe));
+        }
+        assert_eq!(pipe_between(Direction::Up, Direction::Up), None);
+        assert_eq!(openings('S'), None);
+    }
+
+    #[test]
+    fn start_pipes() {
+        assert_eq!(start_pipe(&example("square")), Some('F'));
+        assert_eq!(start_pipe(&example("complex")), Some('F'));
+        assert_eq!(start_pipe(&example("larger")), Some('7'));
+        // Every neighbour connects, but only `J` closes a loop.
+        let map = Day10::parse("F-7..\n|.|..\nL-S-.\n..|..").unwrap();
+        assert_eq!(start_pipe(&map), Some('J'));
+        let err = Day10::parse("S-7\n|.|\nL-.").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn square_loop() {
+        let map = example("square");
+        let (_, main) = main_loop(&map).unwrap();
+        assert_eq!(
+            main.tiles[..3],
+            [Point::new(1, 1), Point::new(2, 1), Point::new(3, 1)]
+        );
+        assert_eq!((main.len(), main.farthest(), main.enclosed()), (8, 4, 1));
+        assert_eq!(Loop::trace(&map, Point::new(1, 1), '7'), None);
+    }
+
+    #[test]
+    fn loops_besides_the_main_one() {
+        let map = Day10::parse("S-7F7\n|.|LJ\nL-JF7\nF7.|L\nLJ.L-").unwrap();
+        let (_, main) = main_loop(&map).unwrap();
+        let others = other_loops(&map, &main);
+        assert_eq!(others.len(), 2);
+        assert_eq!(others[0].tiles[0], Point::new(3, 0));
+        assert_eq!(others[1].tiles[0], Point::new(0, 3));
+        assert!(others.iter().all(|other| other.len() == 4));
+        assert!(other_loops(
+            &example("square"),
+            &main_loop(&example("square")).unwrap().1
+        )
+        .is_empty());
+    }
+}
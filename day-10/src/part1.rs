@@ -0,0 +1,6 @@
+use crate::{loops, Map};
+
+pub fn solve(map: &Map) -> usize {
+    let (_, main) = loops::main_loop(map).expect("checked when parsing");
+    main.farthest()
+}
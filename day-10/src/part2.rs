@@ -0,0 +1,109 @@
+use std::fmt;
+
+use grid::{Grid, Point};
+use tracing::{debug, info, warn};
+
+use crate::{loops, regions::Regions, Map, Method};
+
+/// The main loop drawn with box characters, everything else is `'.'`.
+struct Marked {
+    marked: Grid<char>,
+}
+
+impl fmt::Debug for Marked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f)?;
+        for row in self.marked.iter_rows() {
+            for &ch in row {
+                write!(f, "{} ", ch)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Marked {
+    fn new(map: &Map) -> Self {
+        let marked = Grid::init(map.field.rows(), map.field.cols(), '.');
+        Self { marked }
+    }
+
+    fn mark(&mut self, pos: &Point<usize>, symbol: char) {
+        let symbol = match symbol {
+            'F' => '┌',
+            'J' => '┘',
+            'L' => '└',
+            '7' => '┐',
+            '-' => '─',
+            '|' => '│',
+            'S' => return,
+            _ => symbol,
+        };
+        self.marked[*pos] = symbol;
+    }
+
+    /// Marks the tiles inside the loop with `'x'` and counts them. Scanning
+    /// a row, a tile is inside once the loop crossed it an odd number of
+    /// times before, where only the pipes reaching north count as a crossing:
+    /// `┌─┘` crosses the row and `└─┘` does not.
+    fn calc_inner_outer(&mut self) -> usize {
+        (0..self.marked.rows()).for_each(|row| {
+            let mut inner = false;
+            for c in self.marked.row_mut(row) {
+                match c {
+                    '│' | '└' | '┘' => inner = !inner,
+                    '.' if inner => *c = 'x',
+                    _ => {}
+                }
+            }
+        });
+        self.marked.iter().filter(|&symbol| symbol == &'x').count()
+    }
+}
+
+/// Counts the tiles inside the main loop with `method`, and checks the count
+/// against the one from its area.
+pub fn solve(map: &Map, method: Method) -> usize {
+    let (start_pipe, main) = loops::main_loop(map).expect("checked when parsing");
+    info!(
+        "start tile is a `{start_pipe}` on a loop of {} tiles",
+        main.len()
+    );
+
+    let inside = match method {
+        Method::Scan => {
+            let mut marked = Marked::new(map);
+            marked.mark(&main.tiles[0], start_pipe);
+            for tile in &main.tiles[1..] {
+                marked.mark(tile, map.field[*tile]);
+            }
+            marked.calc_inner_outer()
+        }
+        Method::Flood => {
+            let regions = Regions::new(map, start_pipe, &main);
+            debug!("{} regions:\n{}", regions.count, regions.labelled());
+            regions.enclosed()
+        }
+    };
+
+    let by_area = main.enclosed();
+    if by_area != inside {
+        warn!("{inside} tiles inside with {method:?}, but {by_area} by the area of the loop");
+    }
+
+    let others = loops::other_loops(map, &main);
+    if !others.is_empty() {
+        let lengths: Vec<String> = others
+            .iter()
+            .map(|other| format!("{} tiles from {}", other.len(), other.tiles[0]))
+            .collect();
+        info!(
+            "{} other loops of pipes: {}",
+            others.len(),
+            lengths.join(", ")
+        );
+    }
+
+    inside
+}
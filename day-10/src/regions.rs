@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use grid::{Grid, Point};
+
+use crate::{
+    loops::{openings, Loop},
+    Map,
+};
+
+/// The map with the main loop drawn at three times its size, so that the
+/// gaps between neighbouring pipes are a cell wide, and every connected area
+/// of the rest labelled with its own region.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// The region of each cell, `None` for the loop. Each tile of the map is
+    /// the 3×3 block of cells around `(3 * row + 1, 3 * col + 1)`.
+    pub cells: Grid<Option<usize>>,
+    /// Number of regions. Region 0 is the outside, since the corner cells of
+    /// the tiles are never part of the loop and the ones on the border of the
+    /// map connect all round it.
+    pub count: usize,
+    /// The pipes of the loop, `None` elsewhere.
+    pipes: Grid<Option<char>>,
+}
+
+impl Regions {
+    pub fn new(map: &Map, start_pipe: char, main: &Loop) -> Self {
+        let (rows, cols) = map.field.size();
+        let mut pipes = Grid::init(rows, cols, None);
+        let mut wall = Grid::init(3 * rows, 3 * cols, false);
+        for (i, &tile) in main.tiles.iter().enumerate() {
+            let pipe = if i == 0 { start_pipe } else { map.field[tile] };
+            pipes[tile] = Some(pipe);
+            let centre = Point::new(3 * tile.x + 1, 3 * tile.y + 1);
+            wall[centre] = true;
+            for direction in openings(pipe).into_iter().flatten() {
+                // The centre cell has neighbours all round.
+                let arm = wall.step(centre, direction).unwrap();
+                wall[arm] = true;
+            }
+        }
+
+        let mut cells = Grid::init(3 * rows, 3 * cols, None);
+        let mut count = 0;
+        for row in 0..3 * rows {
+            for col in 0..3 * cols {
+                if wall[(row, col)] || cells[(row, col)].is_some() {
+                    continue;
+                }
+                cells[(row, col)] = Some(count);
+                let mut queue = VecDeque::from([(row, col)]);
+                while let Some((row, col)) = queue.pop_front() {
+                    for next in wall.neighbours(row, col) {
+                        if !wall[next] && cells[next].is_none() {
+                            cells[next] = Some(count);
+                            queue.push_back(next);
+                        }
+                    }
+                }
+                count += 1;
+            }
+        }
+        Regions {
+            cells,
+            count,
+            pipes,
+        }
+    }
+
+    /// The region of the tile at `tile`, `None` if it is part of the loop.
+    pub fn tile(&self, tile: Point<usize>) -> Option<usize> {
+        self.cells[(3 * tile.y + 1, 3 * tile.x + 1)]
+    }
+
+    /// The number of tiles in regions other than the outside.
+    pub fn enclosed(&self) -> usize {
+        let (rows, cols) = self.pipes.size();
+        (0..rows)
+            .flat_map(|y| (0..cols).map(move |x| Point::new(x, y)))
+            .filter(|&tile| self.tile(tile).is_some_and(|region| region != 0))
+            .count()
+    }
+
+    /// The map at its own size with the pipes of the loop, `.` outside and
+    /// the regions within as `1` to `9` and `a` to `z`, counting round again
+    /// after that.
+    pub fn labelled(&self) -> Grid<char> {
+        let (rows, cols) = self.pipes.size();
+        let mut labelled = Grid::init(rows, cols, '.');
+        for y in 0..rows {
+            for x in 0..cols {
+                let tile = Point::new(x, y);
+                labelled[tile] = match (self.pipes[tile], self.tile(tile)) {
+                    (Some(pipe), _) => pipe,
+                    (None, Some(0)) => '.',
+                    (None, region) => {
+                        let region = region.unwrap_or_default() as u32;
+                        char::from_digit((region - 1) % 35 + 1, 36).unwrap()
+                    }
+                };
+            }
+        }
+        labelled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{normalize, Solution};
+
+    use crate::{loops, Day10};
+
+    fn regions(input: &str) -> Regions {
+        let map = Day10::parse(&normalize(input)).unwrap();
+        let (start_pipe, main) = loops::main_loop(&map).unwrap();
+        Regions::new(&map, start_pipe, &main)
+    }
+
+    #[test]
+    fn squeezed_between_pipes() {
+        let regions = regions(include_str!("../res/examples/squeezed"));
+        // The outside reaches in between the pipes, the two pockets are
+        // joined along the inner side of the loop.
+        assert_eq!(regions.count, 2);
+        assert_eq!(regions.enclosed(), 4);
+        assert_eq!(regions.tile(Point::new(3, 3)), Some(0));
+        assert_eq!(regions.tile(Point::new(2, 6)), Some(1));
+        assert_eq!(regions.tile(Point::new(6, 6)), Some(1));
+        assert_eq!(regions.tile(Point::new(1, 1)), None);
+        assert_eq!(
+            regions.labelled().to_string(),
+            "\
+..........
+.F------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|11||11|.
+.L--JL--J.
+.........."
+        );
+    }
+
+    #[test]
+    fn junk_pipes_are_ground() {
+        let regions = regions("F---7\n|F7.|\n|LJ.|\nS---J");
+        assert_eq!(regions.enclosed(), 6);
+        assert_eq!(regions.labelled().row(1), ['|', '1', '1', '1', '|']);
+    }
+}
@@ -0,0 +1,19 @@
+extern crate day_11;
+use common::{normalize, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_11::{Day11, Params};
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let input = Day11::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+    let params = Params {
+        row_expansion: 100,
+        col_expansion: 100,
+    };
+
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day11::part2(black_box(&input), &params))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
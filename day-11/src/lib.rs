@@ -0,0 +1,67 @@
+use common::{parse_param, ParamError, ParseError, Solution};
+use grid::Grid;
+
+pub mod part1;
+pub mod part2;
+pub mod universe;
+
+pub struct Day11;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// How many rows each empty one becomes in part 2.
+    pub row_expansion: usize,
+    /// How many columns each empty one becomes in part 2.
+    pub col_expansion: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row_expansion: 1_000_000,
+            col_expansion: 1_000_000,
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "expansion" => {
+                self.row_expansion = parse_param(key, value)?;
+                self.col_expansion = self.row_expansion;
+            }
+            "row_expansion" => self.row_expansion = parse_param(key, value)?,
+            "col_expansion" => self.col_expansion = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    /// The image, `'#'` marks a galaxy.
+    type Input = Grid<char>;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::parse_with(input, |c| match c {
+            '.' | '#' => Ok(c),
+            _ => Err(c),
+        })?)
+    }
+
+    fn part1(image: &Self::Input, _params: &Params) -> usize {
+        part1::solve(image)
+    }
+
+    fn part2(image: &Self::Input, params: &Params) -> usize {
+        part2::solve(image, params.row_expansion, params.col_expansion)
+    }
+}
+
+common::examples!(Day11);
@@ -0,0 +1,7 @@
+use grid::Grid;
+
+use crate::universe::Universe;
+
+pub fn solve(image: &Grid<char>) -> usize {
+    Universe::new(image, 2, 2).sum_of_distances()
+}
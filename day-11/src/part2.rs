@@ -0,0 +1,7 @@
+use grid::Grid;
+
+use crate::universe::Universe;
+
+pub fn solve(image: &Grid<char>, row_expansion: usize, col_expansion: usize) -> usize {
+    Universe::new(image, row_expansion, col_expansion).sum_of_distances()
+}
@@ -0,0 +1,173 @@
+use grid::{Grid, Point};
+
+/// The galaxies of an image, at their positions once the universe has
+/// expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Universe {
+    /// Where each galaxy is in the image, in reading order.
+    pub galaxies: Vec<Point<usize>>,
+    /// Where each galaxy is after the expansion, in the same order.
+    pub expanded: Vec<Point<usize>>,
+}
+
+impl Universe {
+    /// Each empty row becomes `row_factor` rows, and each empty column
+    /// `col_factor` columns.
+    pub fn new(image: &Grid<char>, row_factor: usize, col_factor: usize) -> Self {
+        let factor = |factor| move |_, empty| if empty { factor } else { 1 };
+        Self::with_weights(image, factor(row_factor), factor(col_factor))
+    }
+
+    /// Each row takes up `row_weight(row, empty)` rows after the expansion,
+    /// and each column `col_weight(col, empty)` columns, where `empty` tells
+    /// whether it has no galaxy.
+    pub fn with_weights(
+        image: &Grid<char>,
+        row_weight: impl Fn(usize, bool) -> usize,
+        col_weight: impl Fn(usize, bool) -> usize,
+    ) -> Self {
+        let galaxies: Vec<Point<usize>> = image
+            .indexed_iter()
+            .filter(|&(_, &c)| c == '#')
+            .map(|((y, x), _)| Point::new(x, y))
+            .collect();
+
+        let mut empty_rows = vec![true; image.rows()];
+        let mut empty_cols = vec![true; image.cols()];
+        for galaxy in &galaxies {
+            empty_rows[galaxy.y] = false;
+            empty_cols[galaxy.x] = false;
+        }
+        // Where each row and column starts after the expansion.
+        let starts = |empty: &[bool], weight: &dyn Fn(usize, bool) -> usize| {
+            empty
+                .iter()
+                .enumerate()
+                .scan(0, |start, (i, &empty)| {
+                    let this = *start;
+                    *start += weight(i, empty);
+                    Some(this)
+                })
+                .collect::<Vec<_>>()
+        };
+        let ys = starts(&empty_rows, &row_weight);
+        let xs = starts(&empty_cols, &col_weight);
+
+        let expanded = galaxies
+            .iter()
+            .map(|galaxy| Point::new(xs[galaxy.x], ys[galaxy.y]))
+            .collect();
+        Universe { galaxies, expanded }
+    }
+
+    pub fn distance(&self, a: usize, b: usize) -> usize {
+        self.expanded[a].manhattan(self.expanded[b])
+    }
+
+    /// The sum of the distances between all pairs of galaxies. The distance
+    /// is the sum of the ones along each axis, and along an axis each
+    /// coordinate, once sorted, is that far from each one before it.
+    pub fn sum_of_distances(&self) -> usize {
+        let xs = self.expanded.iter().map(|galaxy| galaxy.x).collect();
+        let ys = self.expanded.iter().map(|galaxy| galaxy.y).collect();
+        sum_of_differences(xs) + sum_of_differences(ys)
+    }
+
+    /// The other galaxy closest to galaxy `i` and how far it is. Ties go to
+    /// the first galaxy in reading order.
+    pub fn nearest(&self, i: usize) -> Option<(usize, usize)> {
+        self.others(i).min_by_key(|&(_, distance)| distance)
+    }
+
+    /// The other galaxy farthest from galaxy `i` and how far it is. Ties go
+    /// to the first galaxy in reading order.
+    pub fn farthest(&self, i: usize) -> Option<(usize, usize)> {
+        self.others(i)
+            .max_by(|(a, da), (b, db)| da.cmp(db).then(b.cmp(a)))
+    }
+
+    fn others(&self, i: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.expanded.len())
+            .filter(move |&j| j != i)
+            .map(move |j| (j, self.distance(i, j)))
+    }
+}
+
+/// The sum of `|a - b|` over all pairs of `values`.
+fn sum_of_differences(mut values: Vec<usize>) -> usize {
+    values.sort_unstable();
+    let mut before = 0;
+    let mut sum = 0;
+    for (i, &value) in values.iter().enumerate() {
+        sum += value * i - before;
+        before += value;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::{normalize, Solution};
+
+    use crate::Day11;
+
+    fn image() -> Grid<char> {
+        Day11::parse(&normalize(include_str!("../res/examples/example"))).unwrap()
+    }
+
+    fn all_pairs(universe: &Universe) -> usize {
+        let n = universe.expanded.len();
+        (0..n)
+            .flat_map(|a| (a + 1..n).map(move |b| (a, b)))
+            .map(|(a, b)| universe.distance(a, b))
+            .sum()
+    }
+
+    #[test]
+    fn expanded_positions() {
+        let universe = Universe::new(&image(), 2, 2);
+        assert_eq!(universe.galaxies[0], Point::new(3, 0));
+        assert_eq!(universe.expanded[0], Point::new(4, 0));
+        // Galaxies 5 and 9 of the puzzle.
+        assert_eq!(universe.distance(4, 8), 9);
+        assert_eq!(universe.distance(0, 6), 15);
+        assert_eq!(universe.sum_of_distances(), 374);
+    }
+
+    #[test]
+    fn per_axis() {
+        let image = image();
+        for (rows, cols) in [(1, 1), (2, 10), (100, 1), (7, 3)] {
+            let universe = Universe::new(&image, rows, cols);
+            assert_eq!(universe.sum_of_distances(), all_pairs(&universe));
+        }
+        let wide = Universe::new(&image, 1, 1_000);
+        assert_eq!(wide.expanded[0], Point::new(1_002, 0));
+    }
+
+    #[test]
+    fn weights() {
+        // Every other row counts double, whether empty or not.
+        let universe = Universe::with_weights(&image(), |row, _| 1 + row % 2, |_, _| 1);
+        assert_eq!(universe.expanded[1], Point::new(7, 1));
+        assert_eq!(universe.expanded[2], Point::new(0, 3));
+        assert_eq!(universe.sum_of_distances(), all_pairs(&universe));
+    }
+
+    #[test]
+    fn nearest_and_farthest() {
+        let universe = Universe::new(&image(), 2, 2);
+        assert_eq!(universe.nearest(0), Some((1, 6)));
+        // So is galaxy 6, the puzzle's galaxy 7.
+        assert_eq!(universe.farthest(0), Some((5, 15)));
+        assert_eq!(universe.nearest(7), Some((8, 5)));
+        for i in 0..universe.expanded.len() {
+            let (nearest, near) = universe.nearest(i).unwrap();
+            let (farthest, far) = universe.farthest(i).unwrap();
+            assert!(nearest != i && farthest != i && near <= far);
+        }
+        let lonely = Universe::new(&Grid::parse_with("..#", Ok::<_, char>).unwrap(), 2, 2);
+        assert_eq!(lonely.nearest(0), None);
+    }
+}
@@ -1,16 +1,15 @@
 extern crate day_12;
+use common::{normalize, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_12::part2::solve;
+use day_12::{Day12, Params};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
+    let input = Day12::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+    let params = Params { repeat: 2 };
 
-    c.bench_function("solver part2", |b| b.iter(|| solve(black_box(input), 2)));
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day12::part2(black_box(&input), &params))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
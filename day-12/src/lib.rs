@@ -1,2 +1,105 @@
+use common::{
+    check_chars, parse_lines, parse_number, parse_param, split_once, ParamError, ParseError,
+    Solution,
+};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day12;
+
+/// One line of the damaged spring records.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub springs: String,
+    pub groups: Vec<usize>,
+}
+
+impl TryFrom<&str> for Record {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let (springs, groups) = split_once(line, line, " ")?;
+        check_chars(
+            line,
+            springs,
+            |c| matches!(c, '.' | '#' | '?'),
+            "`.`, `#` or `?`",
+        )?;
+        let groups = groups
+            .split(',')
+            .map(|group| match parse_number(line, group)? {
+                0 => Err(ParseError::new(
+                    line,
+                    group,
+                    "a group of at least one spring",
+                )),
+                len => Ok(len),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Record {
+            springs: springs.to_string(),
+            groups,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// How often each record is unfolded in part 2.
+    pub repeat: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { repeat: 5 }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "repeat" => self.repeat = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Record>;
+    type Params = Params;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Record::try_from)
+    }
+
+    fn part1(records: &Self::Input, _params: &Params) -> u128 {
+        part1::solve(records)
+    }
+
+    fn part2(records: &Self::Input, params: &Params) -> u128 {
+        part2::solve(records, params.repeat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_record() {
+        let record = Record::try_from("?#?.# 2,1").unwrap();
+        assert_eq!(record.groups, [2, 1]);
+        let err = Record::try_from("???.### 1,0,3").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (11, "0"));
+        let err = Record::try_from("???.### 1,x").unwrap_err();
+        assert_eq!(err.expected, "a number");
+    }
+}
+
+common::examples!(Day12);
@@ -1,190 +1,137 @@
-use cached::proc_macro::cached;
+use crate::Record;
 
-pub fn solve(input: &str, repeat: usize) -> usize {
-    input.lines().map(|line| process_line(line, repeat)).sum()
+pub fn solve(records: &[Record], repeat: usize) -> u128 {
+    records
+        .iter()
+        .map(|record| process_record(record, repeat))
+        .sum()
 }
 
-fn process_line(line: &str, repeat: usize) -> usize {
-    let (record, groups) = line.split_once(' ').unwrap();
-
-    let record = (0..repeat)
-        .map(|_| record.to_string())
-        .collect::<Vec<String>>()
+fn process_record(record: &Record, repeat: usize) -> u128 {
+    let springs = (0..repeat)
+        .map(|_| record.springs.as_str())
+        .collect::<Vec<&str>>()
         .join("?");
 
-    let groups: Vec<usize> = groups
-        .split(',')
-        .map(|g| g.parse::<usize>().unwrap())
-        .collect::<Vec<usize>>()
-        .repeat(repeat);
-
-    fit(record, groups)
+    count_arrangements(springs.as_bytes(), &record.groups.repeat(repeat))
 }
 
-#[cached]
-fn fit(record: String, groups: Vec<usize>) -> usize {
-    if let Some((gi, &g)) = groups.iter().enumerate().max_by_key(|&(_, g)| g) {
-        if record.len() < g {
-            0
-        } else {
-            let (groups_left, groups_right) = groups.split_at(gi);
-            let groups_right = &groups_right[1..];
-
-            let mut possible_placements = Vec::new();
-            for i in 0..record.len() - g + 1 {
-                if record[i..i + g].contains('.')
-                    || (record[..i].len() + 1
-                        < groups_left.iter().sum::<usize>() + groups_left.len())
-                    || record[i + g..].len() + 1
-                        < groups_right.iter().sum::<usize>() + groups_right.len()
-                {
-                    continue;
-                } else {
-                    if (i == 0 || record.as_bytes()[i - 1] != '#' as u8)
-                        && (i + g == record.len() || record.as_bytes()[i + g] != '#' as u8)
-                    {
-                        possible_placements.push(i)
-                    }
+/// The number of ways to replace each `?` in `springs` with `.` or `#` so
+/// that the runs of `#` have the lengths in `groups`.
+pub(crate) fn count_arrangements(springs: &[u8], groups: &[usize]) -> u128 {
+    let (len, count) = (springs.len(), groups.len());
+    // How many springs from each offset on could be damaged.
+    let mut damaged_run = vec![0; len + 1];
+    for i in (0..len).rev() {
+        if springs[i] != b'.' {
+            damaged_run[i] = damaged_run[i + 1] + 1;
+        }
+    }
+
+    // `ways[i * (count + 1) + j]`: arrangements of the springs from offset
+    // `i` on with the groups from index `j` on, filled from the end.
+    let width = count + 1;
+    let mut ways = vec![0u128; (len + 1) * width];
+    ways[len * width + count] = 1;
+    for i in (0..len).rev() {
+        for j in 0..=count {
+            let mut total = 0;
+            if springs[i] != b'#' {
+                total += ways[(i + 1) * width + j];
+            }
+            if springs[i] != b'.' && j < count {
+                let group = groups[j];
+                let end = i + group;
+                if damaged_run[i] >= group && (end == len || springs[end] != b'#') {
+                    // The spring after the group, if any, is operational.
+                    total += ways[(end + 1).min(len) * width + j + 1];
                 }
             }
-
-            possible_placements
-                .iter()
-                .map(|&placement_index| {
-                    let (mut rec_left, mut rec_right) = record.split_at(placement_index);
-                    let options_left = {
-                        rec_left = if rec_left.len() <= 1 {
-                            ""
-                        } else {
-                            &rec_left[..rec_left.len() - 1]
-                        };
-
-                        if groups_left.len() == 0 {
-                            if rec_left.contains('#') {
-                                0
-                            } else {
-                                1
-                            }
-                        } else {
-                            fit(rec_left.to_string(), groups_left.to_vec())
-                        }
-                    };
-
-                    if options_left > 0 {
-                        let options_right = {
-                            rec_right = if rec_right.len() <= 1 + g {
-                                ""
-                            } else {
-                                &rec_right[1 + g..]
-                            };
-
-                            if groups_right.len() == 0 {
-                                if rec_right.contains('#') {
-                                    0
-                                } else {
-                                    1
-                                }
-                            } else {
-                                fit(rec_right.to_string(), groups_right.to_vec())
-                            }
-                        };
-                        options_left * options_right
-                    } else {
-                        0
-                    }
-                })
-                .sum()
+            ways[i * width + j] = total;
         }
-    } else {
-        0
     }
+    ways[0]
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn example() {
-        let example = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-
-        assert_eq!(solve(example, 1), 21);
-    }
-
     #[test]
     fn test_line_1() {
         let line = "???.### 1,1,3";
-        assert_eq!(process_line(line, 1), 1);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 1);
     }
 
     #[test]
     fn test_line_2() {
         let line = ".??..??...?##. 1,1,3";
-        assert_eq!(process_line(line, 1), 4);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 4);
     }
 
     #[test]
     fn test_line_3() {
         let line = "?#?#?#?#?#?#?#? 1,3,1,6";
-        assert_eq!(process_line(line, 1), 1);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 1);
     }
 
     #[test]
     fn test_line_4() {
         let line = "????.#...#... 4,1,1";
-        assert_eq!(process_line(line, 1), 1);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 1);
     }
 
     #[test]
     fn test_line_5() {
         let line = "????.######..#####. 1,6,5";
-        assert_eq!(process_line(line, 1), 4);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 4);
     }
 
     #[test]
     fn test_line_6() {
         let line = "?###???????? 3,2,1";
-        assert_eq!(process_line(line, 1), 10);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 10);
     }
 
     #[test]
     fn test_fit() {
         let record = ".?#?????###???. 1,6,1";
-        assert_eq!(process_line(record, 1), 3);
+        assert_eq!(process_record(&record.try_into().unwrap(), 1), 3);
     }
 
     #[test]
     fn test_fit_easy() {
         let record = "??? 1";
-        assert_eq!(process_line(record, 1), 3);
+        assert_eq!(process_record(&record.try_into().unwrap(), 1), 3);
     }
 
     #[test]
     fn test_line() {
         let line = "????.?#????#?? 2,1,1,3";
-        assert_eq!(process_line(line, 1), 12);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 12);
     }
 
     #[test]
     fn test_line2() {
         let line = "??.#? 2";
-        assert_eq!(process_line(line, 1), 1);
+        assert_eq!(process_record(&line.try_into().unwrap(), 1), 1);
+    }
+
+    #[test]
+    fn no_groups() {
+        assert_eq!(count_arrangements(b"?.?", &[]), 1);
+        assert_eq!(count_arrangements(b"?#?", &[]), 0);
+        assert_eq!(count_arrangements(b"", &[]), 1);
+        assert_eq!(count_arrangements(b"", &[1]), 0);
     }
 
     #[test]
-    fn example_p2() {
-        let example = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
-
-        assert_eq!(solve(example, 5), 525152);
+    fn beyond_u64() {
+        // `??? 1` unfolded 40 times puts 40 single damaged springs, none next
+        // to another, among 159 springs: 120 choose 40 ways.
+        let record = "??? 1".try_into().unwrap();
+        let binomial = (0..40).fold(1u128, |acc, k| acc * (120 - k) / (k + 1));
+        assert_eq!(process_record(&record, 40), binomial);
+        assert!(binomial > u64::MAX as u128);
     }
 }
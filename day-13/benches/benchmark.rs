@@ -1,11 +1,14 @@
 extern crate day_13;
+use common::{normalize, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_13::part1::solve;
+use day_13::Day13;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = "";
+    let input = Day13::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
 
-    c.bench_function("solver part1", |b| b.iter(|| solve(black_box(input))));
+    c.bench_function("solver part1", |b| {
+        b.iter(|| Day13::part1(black_box(&input), &()))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,2 +1,82 @@
+use std::fmt;
+
+use common::{parse_blocks, ParseError, Solution};
+use grid::Grid;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day13;
+
+/// One pattern of ash (`.`) and rocks (`#`), row by row.
+pub type Field = Grid<char>;
+
+pub fn parse_field(field: &str) -> Result<Field, ParseError> {
+    Ok(Grid::parse_with(field, |c| match c {
+        '.' | '#' => Ok(c),
+        _ => Err(c),
+    })?)
+}
+
+/// Why a field, numbered from 1 in the order of the input, has no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MirrorError {
+    /// The field reflects across no line.
+    NoReflection(usize),
+    /// Fixing no single smudge gives the field a new line of reflection.
+    NoSmudge(usize),
+}
+
+impl fmt::Display for MirrorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MirrorError::NoReflection(field) => {
+                write!(f, "field {field} has no line of reflection")
+            }
+            MirrorError::NoSmudge(field) => {
+                write!(f, "no smudge gives field {field} a new line of reflection")
+            }
+        }
+    }
+}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Field>;
+    type Params = ();
+    type Answer1 = Result<usize, MirrorError>;
+    type Answer2 = Result<usize, MirrorError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_blocks(input, parse_field)
+    }
+
+    fn part1(fields: &Self::Input, _params: &()) -> Result<usize, MirrorError> {
+        part1::solve(fields)
+    }
+
+    fn part2(fields: &Self::Input, _params: &()) -> Result<usize, MirrorError> {
+        part2::solve(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_mirror() {
+        let fields = Day13::parse("##\n##\n\n#.\n.#\n").unwrap();
+        assert_eq!(
+            Day13::part1(&fields, &()),
+            Err(MirrorError::NoReflection(2))
+        );
+        assert_eq!(
+            Day13::part2(&fields, &()).unwrap_err().to_string(),
+            "no smudge gives field 1 a new line of reflection"
+        );
+    }
+}
+
+common::examples!(Day13);
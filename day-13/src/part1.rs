@@ -1,30 +1,25 @@
-pub fn solve(input: &str) -> usize {
-    input.split("\n\n").map(process_field).sum()
+use crate::{Field, MirrorError};
+
+pub fn solve(fields: &[Field]) -> Result<usize, MirrorError> {
+    fields
+        .iter()
+        .zip(1..)
+        .map(|(field, i)| process_field(field).ok_or(MirrorError::NoReflection(i)))
+        .sum()
 }
 
-fn process_field(field: &str) -> usize {
-    let mut field: Vec<Vec<char>> = field.lines().map(|line| line.chars().collect()).collect();
-
-    let reflection_y = find_reflection_vertical(&field);
-
-    if let Some(reflection_y) = reflection_y {
-        reflection_y * 100
-    } else if let Some(reflection_x) = {
-        field = transpose(&field);
-        find_reflection_vertical(&field)
-    } {
-        reflection_x
-    } else {
-        unreachable!()
-    }
+fn process_field(field: &Field) -> Option<usize> {
+    find_reflection_vertical(field)
+        .map(|reflection_y| reflection_y * 100)
+        .or_else(|| find_reflection_vertical(&field.transpose()))
 }
 
-fn find_reflection_vertical(field: &Vec<Vec<char>>) -> Option<usize> {
-    let size = field.len();
+fn find_reflection_vertical(field: &Field) -> Option<usize> {
+    let size = field.rows();
     (1..size).find(|i| {
         let (mut l, mut h) = (i - 1, *i);
         loop {
-            if field[l] != field[h] {
+            if field.row(l) != field.row(h) {
                 return false;
             }
             if l == 0 || h == size - 1 {
@@ -36,39 +31,10 @@ fn find_reflection_vertical(field: &Vec<Vec<char>>) -> Option<usize> {
     })
 }
 
-fn transpose<T>(v: &[Vec<T>]) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    assert!(!v.is_empty());
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    #[test]
-    fn example() {
-        let example = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-        assert_eq!(solve(example), 405);
-    }
+    use crate::parse_field;
 
     #[test]
     fn test_vertical() {
@@ -79,6 +45,6 @@ mod tests {
 #####.##.
 ..##..###
 #....#..#";
-        assert_eq!(process_field(field), 400);
+        assert_eq!(process_field(&parse_field(field).unwrap()), Some(400));
     }
 }
@@ -1,28 +1,34 @@
+use crate::{Field, MirrorError};
+
 #[derive(PartialEq, Eq)]
 enum Reflection {
     Horizontal(usize),
     Vertical(usize),
 }
 
-pub fn solve(input: &str) -> usize {
-    input.split("\n\n").map(process_field).sum()
+pub fn solve(fields: &[Field]) -> Result<usize, MirrorError> {
+    fields
+        .iter()
+        .zip(1..)
+        .map(|(field, i)| process_field(field, i))
+        .sum()
 }
 
-fn process_field(field: &str) -> usize {
-    let mut field: Vec<Vec<char>> = field.lines().map(|line| line.chars().collect()).collect();
+fn process_field(field: &Field, i: usize) -> Result<usize, MirrorError> {
+    let mut field = field.clone();
 
-    let original_reflection = find_reflection(&field, None).unwrap();
+    let original_reflection = find_reflection(&field, None).ok_or(MirrorError::NoReflection(i))?;
 
-    for y in 0..field.len() {
-        for x in 0..field[0].len() {
+    for y in 0..field.rows() {
+        for x in 0..field.cols() {
             swap_field(&mut field, x, y);
 
             if let Some(reflection) = find_reflection(&field, Some(&original_reflection)) {
                 if reflection != original_reflection {
-                    return match reflection {
+                    return Ok(match reflection {
                         Reflection::Horizontal(reflection) => reflection,
                         Reflection::Vertical(reflection) => reflection * 100,
-                    };
+                    });
                 }
                 swap_field(&mut field, x, y);
             } else {
@@ -30,18 +36,18 @@ fn process_field(field: &str) -> usize {
             }
         }
     }
-    unreachable!();
+    Err(MirrorError::NoSmudge(i))
 }
 
-fn swap_field(field: &mut [Vec<char>], x: usize, y: usize) {
-    if field[y][x] == '#' {
-        field[y][x] = '.';
+fn swap_field(field: &mut Field, x: usize, y: usize) {
+    if field[(y, x)] == '#' {
+        field[(y, x)] = '.';
     } else {
-        field[y][x] = '#';
+        field[(y, x)] = '#';
     }
 }
 
-fn find_reflection(field: &Vec<Vec<char>>, ignore: Option<&Reflection>) -> Option<Reflection> {
+fn find_reflection(field: &Field, ignore: Option<&Reflection>) -> Option<Reflection> {
     if let Some(Reflection::Vertical(ignore_line)) = ignore {
         find_reflection_vertical(field, Some(*ignore_line))
     } else {
@@ -50,16 +56,16 @@ fn find_reflection(field: &Vec<Vec<char>>, ignore: Option<&Reflection>) -> Optio
     .map(Reflection::Vertical)
     .or_else(|| {
         if let Some(Reflection::Horizontal(ignore_line)) = ignore {
-            find_reflection_vertical(&transpose(field), Some(*ignore_line))
+            find_reflection_vertical(&field.transpose(), Some(*ignore_line))
         } else {
-            find_reflection_vertical(&transpose(field), None)
+            find_reflection_vertical(&field.transpose(), None)
         }
         .map(Reflection::Horizontal)
     })
 }
 
-fn find_reflection_vertical(field: &Vec<Vec<char>>, ignore: Option<usize>) -> Option<usize> {
-    let size = field.len();
+fn find_reflection_vertical(field: &Field, ignore: Option<usize>) -> Option<usize> {
+    let size = field.rows();
     (1..size).find_map(|i| {
         if let Some(ignore) = ignore {
             if ignore == i {
@@ -68,7 +74,7 @@ fn find_reflection_vertical(field: &Vec<Vec<char>>, ignore: Option<usize>) -> Op
         }
         let (mut l, mut h) = (i - 1, i);
         loop {
-            if field[l] != field[h] {
+            if field.row(l) != field.row(h) {
                 return None;
             }
             if l == 0 || h == size - 1 {
@@ -80,41 +86,12 @@ fn find_reflection_vertical(field: &Vec<Vec<char>>, ignore: Option<usize>) -> Op
     })
 }
 
-fn transpose<T>(v: &[Vec<T>]) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    assert!(!v.is_empty());
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parse_field;
     use rstest::rstest;
 
-    #[test]
-    fn example() {
-        let example = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-        assert_eq!(solve(example), 400);
-    }
-
     #[rstest]
     #[case(
         "#.##..##.
@@ -140,7 +117,7 @@ mod tests {
         ".#.##.#.#..#.
 ....#....#..#
 .#..####..##.
-..#.#########ccar
+..#.#########
 ....#.....##.
 .#####.#..##.
 #..#...###..#
@@ -155,6 +132,6 @@ mod tests {
         11
     )]
     fn test_example(#[case] input: &str, #[case] expected: usize) {
-        assert_eq!(process_field(input), expected);
+        assert_eq!(process_field(&parse_field(input).unwrap(), 1), Ok(expected));
     }
 }
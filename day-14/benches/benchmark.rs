@@ -1,20 +1,15 @@
 extern crate day_14;
+use common::{normalize, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_14::part2::solve;
+use day_14::{Day14, Params};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
+    let input = Day14::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+    let params = Params { cycles: 10 };
 
-    c.bench_function("solver part2", |b| b.iter(|| solve(black_box(input), 10)));
+    c.bench_function("solver part2", |b| {
+        b.iter(|| Day14::part2(black_box(&input), &params))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,2 +1,57 @@
+use common::{parse_param, ParamError, ParseError, Solution};
+use grid::Grid;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day14;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Number of spin cycles in part 2.
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            cycles: 1_000_000_000,
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "cycles" => self.cycles = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Grid<char>;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::parse_with(input, |c| match c {
+            'O' | '#' | '.' => Ok(c),
+            _ => Err(c),
+        })?)
+    }
+
+    fn part1(field: &Self::Input, _params: &Params) -> usize {
+        part1::solve(field)
+    }
+
+    fn part2(field: &Self::Input, params: &Params) -> usize {
+        part2::solve(field, params.cycles)
+    }
+}
+
+common::examples!(Day14);
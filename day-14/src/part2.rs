@@ -1,13 +1,10 @@
-#![allow(dead_code)]
-#![allow(unused_variables)]
-#![allow(unused_assignments)]
+use grid::Grid;
 
 #[must_use]
-pub fn solve(input: &str, cycles: usize) -> usize {
-    let mut field: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    rotate_minus90(&mut field);
+pub fn solve(field: &Grid<char>, cycles: usize) -> usize {
+    let mut field = field.rotate_ccw();
 
-    let mut seen: Vec<Vec<Vec<char>>> = Vec::new();
+    let mut seen: Vec<Grid<char>> = Vec::new();
     for i in 0..cycles {
         field = cycle(field);
         if let Some(first_seen_index) = seen.iter().position(|v| *v == field) {
@@ -17,51 +14,36 @@ pub fn solve(input: &str, cycles: usize) -> usize {
             break;
         }
         seen.push(field.clone());
-
-        dbg!(i);
     }
 
-    rotate_90(&mut field);
+    field = field.rotate_cw();
 
     calculate_val(&field)
 }
 
-fn calculate_val(field: &[Vec<char>]) -> usize {
-    let field_len = field.len();
+fn calculate_val(field: &Grid<char>) -> usize {
+    let field_len = field.rows();
     field
-        .iter()
+        .iter_rows()
         .enumerate()
         .map(|(i, line)| (field_len - i) * line.iter().filter(|&&c| c == 'O').count())
         .sum()
 }
 
-fn get_field_str(field: &[Vec<char>], rotated: bool) -> String {
-    let mut printfield = field.to_owned();
-    if rotated {
-        rotate_90(&mut printfield);
-    };
-
-    printfield
-        .iter()
-        .map(|line| line.iter().collect::<String>())
-        .collect::<Vec<String>>()
-        .join("\n")
+fn roll_field_left(field: &mut Grid<char>) {
+    (0..field.rows()).for_each(|row| roll_stones_left(field.row_mut(row)));
 }
 
-fn roll_field_left(field: &mut [Vec<char>]) {
-    field.iter_mut().for_each(roll_stones_left);
-}
-
-fn cycle(mut field: Vec<Vec<char>>) -> Vec<Vec<char>> {
+fn cycle(mut field: Grid<char>) -> Grid<char> {
     for _ in 0..4 {
         roll_field_left(&mut field);
-        rotate_90(&mut field);
+        field = field.rotate_cw();
     }
     field
 }
 
-fn roll_stones_left(line: &mut Vec<char>) {
-    *line = line
+fn roll_stones_left(line: &mut [char]) {
+    let rolled = line
         .split(|&c| c == '#')
         .flat_map(|substr| {
             let mut substr = Vec::from(substr);
@@ -70,27 +52,7 @@ fn roll_stones_left(line: &mut Vec<char>) {
             substr
         })
         .collect::<Vec<char>>();
-    line.pop();
-}
-
-fn rotate_90(v: &mut Vec<Vec<char>>) {
-    *v = transpose(v);
-    v.iter_mut().for_each(|row| row.reverse());
-}
-
-fn rotate_minus90(v: &mut Vec<Vec<char>>) {
-    v.iter_mut().for_each(|row| row.reverse());
-    *v = transpose(v);
-}
-
-fn transpose<T>(v: &[Vec<T>]) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    assert!(!v.is_empty());
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
+    line.copy_from_slice(&rolled[..line.len()]);
 }
 
 #[cfg(test)]
@@ -98,49 +60,17 @@ mod tests {
     use super::*;
     use rstest::rstest;
 
-    #[test]
-    fn example() {
-        let example = "O....#....
-    O.OO#....#
-    .....##...
-    OO.#O....O
-    .O.....O#.
-    O.#..O.#.#
-    ..O..#O..O
-    .......O..
-    #....###..
-    #OO..#....";
-        assert_eq!(solve(example, 20), 64); // 1_000_000_000
-    }
-
-    #[test]
-    fn example_3c() {
-        let example = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-        assert_eq!(solve(example, 3), 69);
+    fn get_field_str(field: &Grid<char>, rotated: bool) -> String {
+        if rotated {
+            field.rotate_cw().to_string()
+        } else {
+            field.to_string()
+        }
     }
 
     #[test]
     fn test_cycle() {
-        let example = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-
+        let example = include_str!("../res/examples/example");
         let expected = ".....#....
 ....#...O#
 ...OO##...
@@ -151,9 +81,7 @@ O.#..O.#.#
 ......OOOO
 #...O###..
 #..OO#....";
-        let mut field: Vec<Vec<char>> =
-            example.lines().map(|line| line.chars().collect()).collect();
-        rotate_minus90(&mut field);
+        let mut field = Grid::parse(example).unwrap().rotate_ccw();
         field = cycle(field);
         assert_eq!(get_field_str(&field, true), expected);
     }
@@ -170,11 +98,7 @@ O.#..O.#.#
 .......OOO
 #...O###.O
 #.OOO#...O";
-        let field: Vec<Vec<char>> = field_str
-            .lines()
-            .map(|line| line.chars().collect())
-            .collect();
-        assert_eq!(calculate_val(&field), 69);
+        assert_eq!(calculate_val(&Grid::parse(field_str).unwrap()), 69);
     }
 
     #[rstest]
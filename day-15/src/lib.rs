@@ -1,2 +1,117 @@
+use std::fmt;
+
+use common::{check_chars, parse_lines, parse_number, split_once, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day15;
+
+/// What a step does with the lens labelled `label`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// `-`: takes the lens out of its box.
+    Remove,
+    /// `=<focal length>`: puts a lens of that focal length into its box.
+    Insert(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub label: String,
+    pub op: Op,
+}
+
+/// Writes the step as it appears in the input, which part 1 hashes.
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.op {
+            Op::Remove => write!(f, "{}-", self.label),
+            Op::Insert(focal_length) => write!(f, "{}={focal_length}", self.label),
+        }
+    }
+}
+
+/// Parses `<label>-` or `<label>=<focal length>`, with a focal length from 1
+/// to 9 so that the step is written back exactly as it was read.
+fn parse_step(line: &str, step: &str) -> Result<Step, ParseError> {
+    let (label, op) = match step.strip_suffix('-') {
+        Some(label) => (label, Op::Remove),
+        None => {
+            let (label, focal_length) = split_once(line, step, "=")?;
+            if focal_length.len() != 1 {
+                return Err(ParseError::new(
+                    line,
+                    focal_length,
+                    "a focal length from 1 to 9",
+                ));
+            }
+            (label, Op::Insert(parse_number(line, focal_length)?))
+        }
+    };
+    check_chars(line, label, |c| c.is_ascii_lowercase(), "a lowercase label")?;
+    Ok(Step {
+        label: label.to_string(),
+        op,
+    })
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    /// The comma separated initialization steps, newlines are ignored.
+    type Input = Vec<Step>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            line.split(',')
+                .map(|step| parse_step(line, step))
+                .collect::<Result<Vec<_>, _>>()
+        })?;
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(steps: &Self::Input, _params: &()) -> usize {
+        part1::solve(steps)
+    }
+
+    fn part2(steps: &Self::Input, _params: &()) -> usize {
+        part2::solve(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_steps() {
+        let steps = Day15::parse("rn=1,cm-\n").unwrap();
+        assert_eq!(
+            steps,
+            [
+                Step {
+                    label: "rn".to_string(),
+                    op: Op::Insert(1)
+                },
+                Step {
+                    label: "cm".to_string(),
+                    op: Op::Remove
+                },
+            ]
+        );
+        assert_eq!(steps[0].to_string(), "rn=1");
+
+        let err = Day15::parse("rn=1,cm=05\n").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (9, "05"));
+        let err = Day15::parse("rn=x\n").unwrap_err();
+        assert_eq!(err.column, 4);
+        let err = Day15::parse("rN-\n").unwrap_err();
+        assert_eq!(err.column, 2);
+    }
+}
+
+common::examples!(Day15);
@@ -1,17 +1,9 @@
-pub fn solve(input: &str) -> usize {
-    input
-        .split(',')
-        .map(|s| s.chars().fold(0_usize, |acc, ch| (acc + ch as usize) * 17) % 256)
-        .sum()
-}
+use crate::Step;
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub fn solve(steps: &[Step]) -> usize {
+    steps.iter().map(|step| hash(&step.to_string())).sum()
+}
 
-    #[test]
-    fn example() {
-        let example = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(solve(example), 1320);
-    }
+pub(crate) fn hash(s: &str) -> usize {
+    s.chars().fold(0_usize, |acc, ch| (acc + ch as usize) * 17) % 256
 }
@@ -1,21 +1,22 @@
 use indexmap::IndexMap;
 
+use crate::{part1::hash, Op, Step};
+
 #[must_use]
-pub fn solve(input: &str) -> usize {
+pub fn solve(steps: &[Step]) -> usize {
     let mut boxes: Vec<IndexMap<&str, u8>> = vec![IndexMap::new(); 256];
 
-    input
-        .split(',')
-        .map(|substr| (substr, calc_hash(substr.split(['-', '=']).next().unwrap())))
-        .for_each(|(s, box_i)| {
-            if s.contains('=') {
-                let (label, mirror) = s.split_once('=').unwrap();
-                let mirror: u8 = mirror.parse().unwrap();
-                boxes[box_i].insert(label, mirror);
-            } else {
-                let _ = boxes[box_i].shift_remove(&s[0..s.len() - 1]);
+    for step in steps {
+        let lenses = &mut boxes[hash(&step.label)];
+        match step.op {
+            Op::Insert(focal_length) => {
+                lenses.insert(&step.label, focal_length);
+            }
+            Op::Remove => {
+                lenses.shift_remove(step.label.as_str());
             }
-        });
+        }
+    }
 
     boxes
         .iter()
@@ -28,18 +29,3 @@ pub fn solve(input: &str) -> usize {
         } * ibox)
         .sum()
 }
-
-fn calc_hash(s: &str) -> usize {
-    s.chars().fold(0, |acc, ch| (acc + ch as usize) * 17) % 256
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn example() {
-        let example = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(solve(example), 145);
-    }
-}
@@ -1,2 +1,54 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day16;
+
+#[derive(Clone, Copy)]
+pub enum Symbol {
+    Dot,
+    Dash,
+    Pipe,
+    Forwardslash,
+    Backslash,
+}
+
+impl TryFrom<char> for Symbol {
+    type Error = char;
+
+    fn try_from(ch: char) -> Result<Self, Self::Error> {
+        match ch {
+            '.' => Ok(Self::Dot),
+            '-' => Ok(Self::Dash),
+            '|' => Ok(Self::Pipe),
+            '/' => Ok(Self::Forwardslash),
+            '\\' => Ok(Self::Backslash),
+            _ => Err(ch),
+        }
+    }
+}
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid<Symbol>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(grid: &Self::Input, _params: &()) -> usize {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Self::Input, _params: &()) -> usize {
+        part2::solve(grid)
+    }
+}
+
+common::examples!(Day16);
@@ -3,102 +3,26 @@ use std::{
     collections::{HashMap, HashSet},
 };
 
-use grid::Grid;
+use grid::{Direction, Grid, Point};
 
-#[derive(PartialEq, Eq, Hash, Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-#[derive(Clone, Copy)]
-enum Symbol {
-    Dot,
-    Dash,
-    Pipe,
-    Forwardslash,
-    Backslash,
-}
-
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-struct Position {
-    x: usize,
-    y: usize,
-}
-
-impl Position {
-    fn get_up(&self) -> Self {
-        Position {
-            x: self.x,
-            y: self.y.wrapping_sub(1),
-        }
-    }
-
-    fn get_down(&self) -> Self {
-        Position {
-            x: self.x,
-            y: self.y + 1,
-        }
-    }
-
-    fn get_left(&self) -> Self {
-        Position {
-            x: self.x.wrapping_sub(1),
-            y: self.y,
-        }
-    }
-
-    fn get_right(&self) -> Self {
-        Position {
-            x: self.x + 1,
-            y: self.y,
-        }
-    }
-}
-
-impl From<char> for Symbol {
-    fn from(ch: char) -> Self {
-        match ch {
-            '.' => Self::Dot,
-            '-' => Self::Dash,
-            '|' => Self::Pipe,
-            '/' => Self::Forwardslash,
-            '\\' => Self::Backslash,
-            _ => unreachable!(),
-        }
-    }
-}
+use crate::Symbol;
 
 #[must_use]
-pub fn solve(input: &str) -> usize {
-    let grid = Grid::from_vec(
-        input
-            .chars()
-            .filter(|&c| c != '\n')
-            .map(Symbol::from)
-            .collect::<Vec<Symbol>>(),
-        input.lines().next().unwrap().len(),
-    );
-
+pub fn solve(grid: &Grid<Symbol>) -> usize {
     let cols_max = (0..grid.cols())
         .map(|col| {
             max(
                 luminate(
-                    &grid,
+                    grid,
                     &mut HashMap::new(),
                     Direction::Down,
-                    Position { x: col, y: 0 },
+                    Point::new(col, 0),
                 ),
                 luminate(
-                    &grid,
+                    grid,
                     &mut HashMap::new(),
                     Direction::Up,
-                    Position {
-                        x: col,
-                        y: grid.rows() - 1,
-                    },
+                    Point::new(col, grid.rows() - 1),
                 ),
             )
         })
@@ -109,19 +33,16 @@ pub fn solve(input: &str) -> usize {
         .map(|row| {
             max(
                 luminate(
-                    &grid,
+                    grid,
                     &mut HashMap::new(),
                     Direction::Right,
-                    Position { x: 0, y: row },
+                    Point::new(0, row),
                 ),
                 luminate(
-                    &grid,
+                    grid,
                     &mut HashMap::new(),
                     Direction::Left,
-                    Position {
-                        x: grid.cols() - 1,
-                        y: row,
-                    },
+                    Point::new(grid.cols() - 1, row),
                 ),
             )
         })
@@ -132,9 +53,9 @@ pub fn solve(input: &str) -> usize {
 
 fn luminate(
     grid: &Grid<Symbol>,
-    seen: &mut HashMap<Position, HashSet<Direction>>,
+    seen: &mut HashMap<Point<usize>, HashSet<Direction>>,
     direction: Direction,
-    position: Position,
+    position: Point<usize>,
 ) -> usize {
     let is_start = seen.is_empty();
     if let Some(&symbol) = grid.get(position.y, position.x) {
@@ -142,46 +63,30 @@ fn luminate(
             (Direction::Right, Symbol::Dash | Symbol::Dot)
             | (Direction::Up, Symbol::Forwardslash)
             | (Direction::Down, Symbol::Backslash) => {
-                if seen.entry(position).or_default().insert(Direction::Right) {
-                    luminate(grid, seen, Direction::Right, position.get_right());
-                }
+                beam(grid, seen, position, Direction::Right);
             } // Right
             (Direction::Down, Symbol::Pipe | Symbol::Dot)
             | (Direction::Right, Symbol::Backslash)
             | (Direction::Left, Symbol::Forwardslash) => {
-                if seen.entry(position).or_default().insert(Direction::Down) {
-                    luminate(grid, seen, Direction::Down, position.get_down());
-                }
+                beam(grid, seen, position, Direction::Down);
             }
             (Direction::Left, Symbol::Dash | Symbol::Dot)
             | (Direction::Up, Symbol::Backslash)
             | (Direction::Down, Symbol::Forwardslash) => {
-                if seen.entry(position).or_default().insert(Direction::Left) {
-                    luminate(grid, seen, Direction::Left, position.get_left());
-                }
+                beam(grid, seen, position, Direction::Left);
             }
             (Direction::Up, Symbol::Pipe | Symbol::Dot)
             | (Direction::Right, Symbol::Forwardslash)
             | (Direction::Left, Symbol::Backslash) => {
-                if seen.entry(position).or_default().insert(Direction::Up) {
-                    luminate(grid, seen, Direction::Up, position.get_up());
-                }
+                beam(grid, seen, position, Direction::Up);
             }
             (Direction::Right | Direction::Left, Symbol::Pipe) => {
-                if seen.entry(position).or_default().insert(Direction::Up) {
-                    luminate(grid, seen, Direction::Up, position.get_up());
-                }
-                if seen.entry(position).or_default().insert(Direction::Down) {
-                    luminate(grid, seen, Direction::Down, position.get_down());
-                }
+                beam(grid, seen, position, Direction::Up);
+                beam(grid, seen, position, Direction::Down);
             } // Up and Down
             (Direction::Up | Direction::Down, Symbol::Dash) => {
-                if seen.entry(position).or_default().insert(Direction::Left) {
-                    luminate(grid, seen, Direction::Left, position.get_left());
-                }
-                if seen.entry(position).or_default().insert(Direction::Right) {
-                    luminate(grid, seen, Direction::Right, position.get_right());
-                }
+                beam(grid, seen, position, Direction::Left);
+                beam(grid, seen, position, Direction::Right);
             }
         }
     };
@@ -192,23 +97,17 @@ fn luminate(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    // use rstest::rstest;
-
-    #[test]
-    fn example() {
-        let example = ".|...\\....
-|.-.\\.....
-.....|-...
-........|.
-..........
-.........\\
-..../.\\\\..
-.-.-/..|..
-.|....-|.\\
-..//.|....";
-        assert_eq!(solve(example), 51);
+/// Marks the beam leaving `position` towards `direction` and follows it, unless
+/// that beam was already traced or leaves the grid.
+fn beam(
+    grid: &Grid<Symbol>,
+    seen: &mut HashMap<Point<usize>, HashSet<Direction>>,
+    position: Point<usize>,
+    direction: Direction,
+) {
+    if seen.entry(position).or_default().insert(direction) {
+        if let Some(next) = grid.step(position, direction) {
+            luminate(grid, seen, direction, next);
+        }
     }
 }
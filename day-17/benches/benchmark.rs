@@ -1,11 +1,14 @@
 extern crate day_17;
+use common::{normalize, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_xx::part1::solve;
+use day_17::Day17;
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = "";
+    let input = Day17::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
 
-    c.bench_function("solver part1", |b| b.iter(|| solve(black_box(input))));
+    c.bench_function("solver part1", |b| {
+        b.iter(|| Day17::part1(black_box(&input), &()))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,2 +1,143 @@
+use std::fmt;
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+use pathfinding::prelude::astar;
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day17;
+
+/// The crucible cannot reach the bottom right block within its run lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPath(pub Crucible);
+
+impl fmt::Display for NoPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no path to the bottom right with runs of {} to {} blocks",
+            self.0.min_run, self.0.max_run
+        )
+    }
+}
+
+/// How far a crucible goes in one direction: at least `min_run` blocks
+/// before it may turn or stop, and at most `max_run` blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    pub min_run: usize,
+    pub max_run: usize,
+}
+
+impl Crucible {
+    /// The least heat lost on the way from the top left to the bottom right
+    /// block.
+    pub fn least_heat_loss(&self, grid: &Grid<isize>) -> Result<isize, NoPath> {
+        let max = Point::new(grid.cols() as isize - 1, grid.rows() as isize - 1);
+
+        let result = astar(
+            &Pos {
+                pos: Point::new(0, 0),
+                last_dir: None,
+                last_n_steps: 0,
+            },
+            |p| {
+                p.successors(self, max)
+                    .into_iter()
+                    .map(|other| (other, other.cost(grid)))
+            },
+            |p| p.pos.manhattan(max),
+            |p| p.pos == max && p.last_n_steps >= self.min_run,
+        );
+
+        result.map(|(_, heat_loss)| heat_loss).ok_or(NoPath(*self))
+    }
+}
+
+/// A search state: where the crucible is and how it got there. `last_dir` is
+/// `None` only at the start.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Pos {
+    pos: Point<isize>,
+    last_dir: Option<Direction>,
+    last_n_steps: usize,
+}
+
+impl Pos {
+    fn cost(&self, grid: &Grid<isize>) -> isize {
+        *grid.get_signed(self.pos.y, self.pos.x).unwrap()
+    }
+
+    /// The states one block on, within the grid up to `max`.
+    fn successors(&self, crucible: &Crucible, max: Point<isize>) -> Vec<Pos> {
+        Direction::ALL
+            .into_iter()
+            .map(|direction| Pos {
+                pos: self.pos.step(direction),
+                last_dir: Some(direction),
+                last_n_steps: if Some(direction) == self.last_dir {
+                    self.last_n_steps + 1
+                } else {
+                    1
+                },
+            })
+            .filter(|pos| {
+                pos.pos.x >= 0
+                    && pos.pos.x <= max.x
+                    && pos.pos.y >= 0
+                    && pos.pos.y <= max.y
+                    && pos.last_n_steps <= crucible.max_run
+                    && pos.last_dir != self.last_dir.map(Direction::opposite)
+                    && !(self.last_dir.is_some()
+                        && self.last_n_steps < crucible.min_run
+                        && self.last_dir != pos.last_dir)
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    /// Heat loss per city block.
+    type Input = Grid<isize>;
+    type Params = ();
+    type Answer1 = Result<isize, NoPath>;
+    type Answer2 = Result<isize, NoPath>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::parse_with(input, |c| {
+            c.to_digit(10).map(|d| d as isize).ok_or(c)
+        })?)
+    }
+
+    fn part1(grid: &Self::Input, _params: &()) -> Result<isize, NoPath> {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Self::Input, _params: &()) -> Result<isize, NoPath> {
+        part2::solve(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_path() {
+        let grid = Day17::parse("12\n34\n").unwrap();
+        assert_eq!(Day17::part1(&grid, &()), Ok(6));
+        assert_eq!(
+            Day17::part2(&grid, &()),
+            Err(NoPath(Crucible {
+                min_run: 4,
+                max_run: 10
+            }))
+        );
+    }
+}
+
+common::examples!(Day17);
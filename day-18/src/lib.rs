@@ -1,2 +1,130 @@
+use common::{check_chars, parse_lines, parse_number, ParseError, Solution};
+use grid::{geometry, Direction, Point};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day18;
+
+/// One line of the dig plan, e.g. `R 6 (#70c710)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub length: isize,
+    /// The instruction hidden in the colour code, which part 2 follows: five
+    /// hex digits of length followed by the direction.
+    pub hidden_direction: Direction,
+    pub hidden_length: isize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let mut parts = line.split_whitespace();
+        let mut next = |expected: &str| {
+            parts
+                .next()
+                .ok_or_else(|| ParseError::new(line, "", expected))
+        };
+
+        let direction = next("a direction")?;
+        let direction = match direction.chars().collect::<Vec<_>>()[..] {
+            [c @ ('U' | 'D' | 'L' | 'R')] => Direction::try_from(c).ok(),
+            _ => None,
+        }
+        .ok_or_else(|| ParseError::new(line, direction, "`U`, `D`, `L` or `R`"))?;
+
+        let length = parse_number(line, next("a length")?)?;
+
+        let color = next("a colour code")?;
+        let hex = color
+            .strip_prefix("(#")
+            .and_then(|hex| hex.strip_suffix(')'))
+            .filter(|hex| hex.len() == 6)
+            .ok_or_else(|| ParseError::new(line, color, "a colour code like `(#70c710)`"))?;
+        let (hidden_length, hidden_direction) = hex.split_at(5);
+        check_chars(
+            line,
+            hidden_length,
+            |c| c.is_ascii_hexdigit(),
+            "a hex digit",
+        )?;
+        let hidden_length = isize::from_str_radix(hidden_length, 16)
+            .map_err(|_| ParseError::new(line, hidden_length, "a hex length"))?;
+        let hidden_direction = match hidden_direction {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => {
+                return Err(ParseError::new(
+                    line,
+                    hidden_direction,
+                    "a direction from 0 to 3",
+                ))
+            }
+        };
+
+        Ok(Instruction {
+            direction,
+            length,
+            hidden_direction,
+            hidden_length,
+        })
+    }
+}
+
+/// The cubic metres of lava the lagoon dug along `steps` holds: the trench
+/// around it and the ground inside, by the shoelace formula and Pick's
+/// theorem.
+pub fn lagoon_size(steps: impl Iterator<Item = (Direction, isize)>) -> isize {
+    let vertices: Vec<Point<isize>> = steps
+        .scan(Point::new(0, 0), |pos, (direction, length)| {
+            *pos = pos.step_by(direction, length);
+            Some(*pos)
+        })
+        .collect();
+
+    geometry::interior_points(&vertices) + geometry::boundary_points(&vertices)
+}
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Vec<Instruction>;
+    type Params = ();
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::try_from)
+    }
+
+    fn part1(plan: &Self::Input, _params: &()) -> isize {
+        part1::solve(plan)
+    }
+
+    fn part2(plan: &Self::Input, _params: &()) -> isize {
+        part2::solve(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hidden_instruction() {
+        let instruction = Instruction::try_from("R 6 (#70c710)").unwrap();
+        assert_eq!(instruction.hidden_direction, Direction::Right);
+        assert_eq!(instruction.hidden_length, 461_937);
+
+        let err = Instruction::try_from("R 6 (#70c714)").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (12, "4"));
+        let err = Instruction::try_from("R 6 (#70g710)").unwrap_err();
+        assert_eq!(err.column, 9);
+    }
+}
+
+common::examples!(Day18);
@@ -1,2 +1,281 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{blocks, parse_lines, parse_number, split_once, ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
+
+pub struct Day19;
+
+/// The workflows by label and the parts waiting to be sorted.
+#[derive(Debug, Clone)]
+pub struct System {
+    pub workflows: HashMap<String, Vec<Rule>>,
+    pub parts: Vec<Part>,
+}
+
+impl TryFrom<&str> for System {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut blocks = blocks(input);
+        let missing = || ParseError::missing("workflows and parts separated by a blank line");
+        let (first, workflows) = blocks.next().ok_or_else(missing)?;
+        let (offset, parts) = blocks.next().ok_or_else(missing)?;
+
+        let labels: HashSet<&str> = workflows
+            .lines()
+            .filter_map(|line| line.split_once('{'))
+            .map(|(label, _)| label)
+            .collect();
+        if !labels.contains(ENTRY) {
+            return Err(ParseError::missing(format!("a workflow `{ENTRY}`")));
+        }
+        let workflows = parse_lines(workflows, |line| parse_workflow(line, &labels))
+            .map_err(|err| err.offset(first))?
+            .into_iter()
+            .collect();
+        let parts = parse_lines(parts, Part::try_from).map_err(|err| err.offset(offset))?;
+
+        Ok(System { workflows, parts })
+    }
+}
+
+/// The workflow every part starts in.
+pub const ENTRY: &str = "in";
+
+/// One workflow line, e.g. `px{a<2006:qkq,m>2090:A,rfg}`, whose rules may
+/// only send parts on to workflows in `labels`.
+fn parse_workflow(line: &str, labels: &HashSet<&str>) -> Result<(String, Vec<Rule>), ParseError> {
+    let (label, rules) = split_once(line, line, "{")?;
+    let list = rules
+        .strip_suffix('}')
+        .ok_or_else(|| ParseError::new(line, rules, "rules ending in `}`"))?;
+    let next = |next: &str| match Next::from(next) {
+        Next::Workflow(label) if !labels.contains(label.as_str()) => Err(ParseError::new(
+            line,
+            next,
+            "a defined workflow, `A` or `R`",
+        )),
+        next => Ok(next),
+    };
+    let rules: Vec<Rule> = list
+        .split(',')
+        .map(|rule| match rule.split_once(':') {
+            None => Ok(Rule {
+                cond: Condition::Pass,
+                next: next(rule)?,
+            }),
+            Some((cond, to)) => Ok(Rule {
+                cond: Condition::try_from(cond)
+                    .map_err(|_| ParseError::new(line, cond, "a condition like `a<2006`"))?,
+                next: next(to)?,
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+    // Without a last rule that always applies, a part could run out of rules.
+    if !matches!(
+        rules.last(),
+        Some(Rule {
+            cond: Condition::Pass,
+            ..
+        })
+    ) {
+        let last = list.rsplit(',').next().unwrap_or(list);
+        return Err(ParseError::new(
+            line,
+            last,
+            "a last rule without a condition",
+        ));
+    }
+    Ok((label.to_string(), rules))
+}
+
+#[derive(Debug, Clone)]
+pub struct Part {
+    pub x: u16,
+    pub m: u16,
+    pub a: u16,
+    pub s: u16,
+}
+
+/// One part line, e.g. `{x=787,m=2655,a=1222,s=2876}`.
+impl TryFrom<&str> for Part {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let expected = "ratings like `{x=787,m=2655,a=1222,s=2876}`";
+        let ratings: Vec<u16> = line
+            .strip_prefix('{')
+            .and_then(|ratings| ratings.strip_suffix('}'))
+            .ok_or_else(|| ParseError::new(line, line, expected))?
+            .split(',')
+            .zip(["x=", "m=", "a=", "s="])
+            .map(|(rating, name)| match rating.strip_prefix(name) {
+                Some(value) => parse_number(line, value),
+                None => Err(ParseError::new(line, rating, format!("`{name}`"))),
+            })
+            .collect::<Result<_, _>>()?;
+        if let [x, m, a, s] = *ratings.as_slice() {
+            Ok(Self { x, m, a, s })
+        } else {
+            Err(ParseError::new(line, line, expected))
+        }
+    }
+}
+
+impl Part {
+    pub fn rating(&self, cat: &Category) -> u16 {
+        match cat {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+
+    pub fn check(&self, cond: &Condition) -> bool {
+        match cond {
+            Condition::Gt(cat, cmp) => self.rating(cat) > *cmp,
+            Condition::Lt(cat, cmp) => self.rating(cat) < *cmp,
+            Condition::Pass => true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<&str> for Category {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s {
+            "x" => Ok(Self::X),
+            "m" => Ok(Self::M),
+            "a" => Ok(Self::A),
+            "s" => Ok(Self::S),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Next {
+    Workflow(String),
+    Accepted,
+    Rejected,
+}
+
+impl From<&str> for Next {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Self::Accepted,
+            "R" => Self::Rejected,
+            _ => Self::Workflow(s.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Condition {
+    Gt(Category, u16),
+    Lt(Category, u16),
+    Pass,
+}
+
+impl TryFrom<&str> for Condition {
+    type Error = ();
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let (cat, rating) = s.split_once(['<', '>']).ok_or(())?;
+        let cat = Category::try_from(cat)?;
+        let rating = rating.parse().map_err(|_| ())?;
+        if s.contains('<') {
+            Ok(Self::Lt(cat, rating))
+        } else {
+            Ok(Self::Gt(cat, rating))
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pub cond: Condition,
+    pub next: Next,
+}
+
+/// Runs `part` through the workflows from [`ENTRY`] on until it is accepted
+/// or rejected. `None` if it is sent to a workflow that does not exist or
+/// runs out of rules, which the parser rules out.
+pub fn qualify<'a>(part: &Part, workflows: &'a HashMap<String, Vec<Rule>>) -> Option<&'a Next> {
+    let mut rules = workflows.get(ENTRY)?;
+    loop {
+        let rule = rules.iter().find(|rule| part.check(&rule.cond))?;
+        match &rule.next {
+            Next::Workflow(label) => rules = workflows.get(label)?,
+            next => return Some(next),
+        }
+    }
+}
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    type Input = System;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        System::try_from(input)
+    }
+
+    fn part1(system: &Self::Input, _params: &()) -> usize {
+        part1::solve(system)
+    }
+
+    fn part2(system: &Self::Input, _params: &()) -> usize {
+        part2::solve(system)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undefined_workflow() {
+        let err = Day19::parse("in{x>10:zz,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 9, "zz"));
+        let err = Day19::parse("in{x>10:A,px}\npx{qq}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (2, "qq"));
+    }
+
+    #[test]
+    fn missing_entry() {
+        let err = Day19::parse("px{x>10:R,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!(err.line, 0);
+        assert!(err.expected.contains("`in`"));
+    }
+
+    #[test]
+    fn no_last_rule() {
+        let err = Day19::parse("in{x>10:R,a<5:A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap_err();
+        assert_eq!((err.line, err.token.as_str()), (1, "a<5:A"));
+    }
+
+    #[test]
+    fn entry_only_accepts_or_rejects() {
+        let system = Day19::parse("in{x>10:A,A}\n\n{x=1,m=2,a=3,s=4}\n").unwrap();
+        assert_eq!(Day19::part1(&system, &()), 10);
+        assert_eq!(Day19::part2(&system, &()), 4000usize.pow(4));
+    }
+}
+
+common::examples!(Day19);
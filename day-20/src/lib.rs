@@ -1 +1,219 @@
-pub mod part1plus2;
+use std::collections::{HashMap, VecDeque};
+
+use common::{parse_lines, parse_param, split_once, ParamError, ParseError, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day20;
+
+/// The module configuration together with the state of every flip-flop and
+/// conjunction. Module names are interned, pulses refer to modules by index.
+#[derive(Debug, Clone)]
+pub struct Circuit {
+    modules: Vec<Module>,
+    indices: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub name: String,
+    pub modtype: ModType,
+    pub outputs: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ModType {
+    Broadcaster,
+    FlipFlop(bool),
+    /// Last level received from each input module.
+    Conjunction(HashMap<usize, bool>),
+    Output,
+    Button,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub level: bool,
+}
+
+/// One line of the module configuration, e.g. `%a -> inv, con`.
+fn parse_definition(line: &str) -> Result<(&str, ModType, Vec<&str>), ParseError> {
+    let (module, outputs) = split_once(line, line, " -> ")?;
+    let outputs = outputs.split(", ").collect::<Vec<&str>>();
+    let (name, modtype) = if let Some(name) = module.strip_prefix('%') {
+        (name, ModType::FlipFlop(false))
+    } else if let Some(name) = module.strip_prefix('&') {
+        (name, ModType::Conjunction(HashMap::new()))
+    } else if module == "broadcaster" {
+        (module, ModType::Broadcaster)
+    } else {
+        let expected = "`broadcaster` or a module name prefixed by `%` or `&`";
+        return Err(ParseError::new(line, module, expected));
+    };
+    Ok((name, modtype, outputs))
+}
+
+impl TryFrom<&str> for Circuit {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut definitions = parse_lines(input, parse_definition)?;
+        definitions.push(("button", ModType::Button, vec!["broadcaster"]));
+
+        let mut indices: HashMap<String, usize> = definitions
+            .iter()
+            .enumerate()
+            .map(|(i, (name, _, _))| (name.to_string(), i))
+            .collect();
+        let mut modules: Vec<Module> = Vec::with_capacity(definitions.len());
+        for (name, modtype, outputs) in &definitions {
+            let outputs = outputs
+                .iter()
+                .map(|&output| {
+                    let next = indices.len();
+                    *indices.entry(output.to_string()).or_insert(next)
+                })
+                .collect();
+            modules.push(Module {
+                name: name.to_string(),
+                modtype: modtype.clone(),
+                outputs,
+            });
+        }
+
+        // Modules that only appear as outputs, like `rx`
+        let mut untyped: Vec<(&String, &usize)> = indices
+            .iter()
+            .filter(|(_, &i)| i >= modules.len())
+            .collect();
+        untyped.sort_by_key(|(_, &i)| i);
+        for (name, _) in untyped {
+            modules.push(Module {
+                name: name.clone(),
+                modtype: ModType::Output,
+                outputs: Vec::new(),
+            });
+        }
+
+        for from in 0..modules.len() {
+            for to in modules[from].outputs.clone() {
+                if let ModType::Conjunction(inputs) = &mut modules[to].modtype {
+                    inputs.insert(from, false);
+                }
+            }
+        }
+
+        Ok(Self { modules, indices })
+    }
+}
+
+impl Circuit {
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indices.get(name).copied()
+    }
+
+    pub fn module(&self, index: usize) -> &Module {
+        &self.modules[index]
+    }
+
+    /// All modules that send pulses to `index`.
+    pub fn inputs(&self, index: usize) -> Vec<usize> {
+        (0..self.modules.len())
+            .filter(|&i| self.modules[i].outputs.contains(&index))
+            .collect()
+    }
+
+    /// Pushes the button once and processes pulses until the circuit settles,
+    /// handing every pulse to `on_pulse` in the order it is delivered.
+    pub fn press_button(&mut self, mut on_pulse: impl FnMut(&Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: self.index("button").unwrap(),
+            to: self.index("broadcaster").unwrap(),
+            level: false,
+        }]);
+
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(&pulse);
+            queue.extend(self.pulse(pulse));
+        }
+    }
+
+    fn pulse(&mut self, pulse: Pulse) -> Vec<Pulse> {
+        let target = &mut self.modules[pulse.to];
+        let output_level = match &mut target.modtype {
+            ModType::Button => unreachable!(),
+            ModType::Broadcaster => pulse.level,
+            ModType::FlipFlop(state) => {
+                if pulse.level {
+                    return Vec::new();
+                }
+                *state = !*state;
+                *state
+            }
+            ModType::Conjunction(inputs) => {
+                *inputs.get_mut(&pulse.from).unwrap() = pulse.level;
+                !inputs.values().all(|&level| level)
+            }
+            ModType::Output => return Vec::new(),
+        };
+        target
+            .outputs
+            .iter()
+            .map(|&output| Pulse {
+                from: pulse.to,
+                to: output,
+                level: output_level,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// How often part 2 presses the button at most before giving up.
+    pub max_presses: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            max_presses: 1_000_000,
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "max_presses" => self.max_presses = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Circuit;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, part2::RxError>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Circuit::try_from(input)
+    }
+
+    fn part1(circuit: &Self::Input, _params: &Params) -> usize {
+        part1::solve(circuit)
+    }
+
+    fn part2(circuit: &Self::Input, params: &Params) -> Result<usize, part2::RxError> {
+        part2::solve(circuit, params.max_presses)
+    }
+}
+
+common::examples!(Day20);
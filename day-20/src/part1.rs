@@ -0,0 +1,14 @@
+use crate::Circuit;
+
+pub fn solve(circuit: &Circuit) -> usize {
+    let mut circuit = circuit.clone();
+    let (mut low, mut high) = (0, 0);
+
+    for _ in 0..1000 {
+        circuit.press_button(|pulse| match pulse.level {
+            false => low += 1,
+            true => high += 1,
+        });
+    }
+    low * high
+}
@@ -0,0 +1,130 @@
+use std::{collections::HashMap, fmt};
+
+use num_integer::lcm;
+use tracing::{event, Level};
+
+use crate::{Circuit, ModType};
+
+/// Why the presses until `rx` gets a low pulse cannot be counted this way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RxError {
+    /// There is no module `rx`.
+    NoRx,
+    /// `rx` is not fed by exactly one conjunction.
+    NotOneConjunction,
+    /// The conjunction feeding `rx` has no inputs, so it never sends a low
+    /// pulse.
+    NoInputs(String),
+    /// Some inputs of the conjunction feeding `rx` did not send a high pulse
+    /// within the limit of presses.
+    NeverHigh { presses: usize, silent: Vec<String> },
+}
+
+impl fmt::Display for RxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RxError::NoRx => write!(f, "the circuit has no module `rx`"),
+            RxError::NotOneConjunction => write!(f, "`rx` is not fed by a single conjunction"),
+            RxError::NoInputs(hub) => write!(f, "`{hub}` feeds `rx` but has no inputs"),
+            RxError::NeverHigh { presses, silent } => write!(
+                f,
+                "{} sent no high pulse in {presses} presses",
+                silent.join(", ")
+            ),
+        }
+    }
+}
+
+/// `rx` is fed by a single conjunction, which only sends a low pulse once all
+/// of its inputs sent a high pulse during the same button press. Each of those
+/// inputs is driven by an independent counter that fires periodically, so the
+/// answer is the least common multiple of the first press each one fires on.
+/// Gives up after `max_presses`.
+pub fn solve(circuit: &Circuit, max_presses: usize) -> Result<usize, RxError> {
+    let rx = circuit.index("rx").ok_or(RxError::NoRx)?;
+    let hub = match circuit.inputs(rx).as_slice() {
+        &[hub] if matches!(circuit.module(hub).modtype, ModType::Conjunction(_)) => hub,
+        _ => return Err(RxError::NotOneConjunction),
+    };
+    let feeders = circuit.inputs(hub);
+    if feeders.is_empty() {
+        return Err(RxError::NoInputs(circuit.module(hub).name.clone()));
+    }
+
+    let mut circuit = circuit.clone();
+    let mut first_high: HashMap<usize, usize> = HashMap::new();
+    let mut presses = 0;
+    while first_high.len() < feeders.len() {
+        if presses == max_presses {
+            let silent = feeders
+                .iter()
+                .filter(|feeder| !first_high.contains_key(feeder))
+                .map(|&feeder| circuit.module(feeder).name.clone())
+                .collect();
+            return Err(RxError::NeverHigh { presses, silent });
+        }
+        presses += 1;
+        circuit.press_button(|pulse| {
+            if pulse.to == hub && pulse.level {
+                first_high.entry(pulse.from).or_insert(presses);
+            }
+        });
+    }
+
+    for (feeder, presses) in &first_high {
+        event!(
+            Level::DEBUG,
+            "{} high at {} button presses",
+            circuit.module(*feeder).name,
+            presses
+        );
+    }
+    Ok(first_high
+        .values()
+        .fold(1, |acc, &presses| lcm(acc, presses)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::Solution;
+
+    use crate::Day20;
+
+    fn solve(input: &str, max_presses: usize) -> Result<usize, RxError> {
+        super::solve(&Day20::parse(input).unwrap(), max_presses)
+    }
+
+    #[test]
+    fn rx_wiring() {
+        assert_eq!(
+            solve("broadcaster -> a\n%a -> b\n&b -> out", 10),
+            Err(RxError::NoRx)
+        );
+        assert_eq!(
+            solve("broadcaster -> a\n%a -> rx", 10),
+            Err(RxError::NotOneConjunction)
+        );
+        assert_eq!(
+            solve("broadcaster -> a\n&a -> rx\n&b -> rx", 10),
+            Err(RxError::NotOneConjunction)
+        );
+        assert_eq!(
+            solve("broadcaster -> a\n%a -> out\n&hub -> rx", 10),
+            Err(RxError::NoInputs("hub".into()))
+        );
+    }
+
+    #[test]
+    fn gives_up() {
+        // `c` only sends high pulses, which the flip-flop `b` ignores.
+        let input = "broadcaster -> a, c\n%a -> hub\n&c -> b\n%b -> hub\n&hub -> rx";
+        assert_eq!(
+            solve(input, 100),
+            Err(RxError::NeverHigh {
+                presses: 100,
+                silent: vec!["b".into()],
+            })
+        );
+    }
+}
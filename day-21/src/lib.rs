@@ -1,3 +1,99 @@
+use std::fmt::Display;
+
+use common::{parse_param, ParamError, ParseError, Solution};
+use grid::Grid;
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct Day21;
+
+pub enum Terrain {
+    Garden,
+    Rock,
+}
+
+impl TryFrom<char> for Terrain {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Self::Rock),
+            '.' | 'S' => Ok(Self::Garden),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Display for Terrain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Terrain::Garden => write!(f, "."),
+            Terrain::Rock => write!(f, "#"),
+        }
+    }
+}
+
+pub struct Garden {
+    pub grid: Grid<Terrain>,
+    /// (x, y) of the `S` tile
+    pub start: (isize, isize),
+}
+
+impl TryFrom<&str> for Garden {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let grid = Grid::parse(input)?;
+        // Only ASCII tiles made it through, byte offsets are columns.
+        let (col, row) = input
+            .lines()
+            .enumerate()
+            .find_map(|(row, line)| line.find('S').map(|col| (col, row)))
+            .ok_or(ParseError::missing("a start tile `S`"))?;
+        Ok(Garden {
+            grid,
+            start: (col as isize, row as isize),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    /// Number of steps the elf takes, 64 in part 1 and 26501365 in part 2
+    /// unless given.
+    pub steps: Option<isize>,
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "steps" => self.steps = Some(parse_param(key, value)?),
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input = Garden;
+    type Params = Params;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Garden::try_from(input)
+    }
+
+    fn part1(garden: &Self::Input, params: &Params) -> isize {
+        part1::solve(garden, params.steps.unwrap_or(64))
+    }
+
+    fn part2(garden: &Self::Input, params: &Params) -> isize {
+        part2::solve(garden, params.steps.unwrap_or(26501365))
+    }
+}
+
+common::examples!(Day21);
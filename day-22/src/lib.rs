@@ -1,3 +1,135 @@
+use common::{parse_lines, parse_number, split_once, ParseError, Solution};
+use tracing::{event, instrument, Level};
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct Day22;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Brick {
+    pub x: (u16, u16),
+    pub y: (u16, u16),
+    pub z: (u16, u16),
+    pub falling: bool,
+    pub has_fallen: bool,
+}
+
+/// One snapshot line, e.g. `1,0,1~1,2,1`.
+impl TryFrom<&str> for Brick {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let corner = |corner: &str| -> Result<[u16; 3], ParseError> {
+            corner
+                .split(',')
+                .map(|x| parse_number(line, x))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|_| ParseError::new(line, corner, "three coordinates"))
+        };
+        let (start, end) = split_once(line, line, "~")?;
+        let (start, end) = (corner(start)?, corner(end)?);
+
+        let x = (start[0], end[0]);
+        let y = (start[1], end[1]);
+        let z = (start[2], end[2]);
+
+        Ok(Brick {
+            x,
+            y,
+            z,
+            falling: !(z.0 == 1 || z.1 == 1),
+            has_fallen: false,
+        })
+    }
+}
+
+impl Brick {
+    pub fn is_blocking(&self, other: &Brick) -> bool {
+        if self.x.1 < other.x.0 || self.x.0 > other.x.1 {
+            return false;
+        }
+        if self.y.1 < other.y.0 || self.y.0 > other.y.1 {
+            return false;
+        }
+        if self.z.1 + 1 < other.z.0 || self.z.0 + 1 > other.z.1 {
+            return false;
+        }
+        true
+    }
+}
+
+#[instrument (level = Level::DEBUG, skip(bricks))]
+pub fn gravitate(bricks: &mut [Brick]) -> usize {
+    let mut can_fall: Vec<usize> = Vec::new();
+    let mut landet: Vec<usize> = Vec::new();
+
+    bricks
+        .iter()
+        .enumerate()
+        .filter(|(_, brick)| brick.falling)
+        .for_each(|(id, brick)| {
+            if let Some(other) = bricks
+                .iter()
+                .filter(|&other| brick != other)
+                .find(|other| other.is_blocking(brick))
+            {
+                event!(
+                    Level::DEBUG,
+                    "Brick {id} {:?} blocked by {id} {:?}",
+                    brick,
+                    other
+                );
+                if !other.falling {
+                    landet.push(id)
+                }
+            } else {
+                event!(Level::DEBUG, "Brick {id} {:?} can fall", brick);
+                can_fall.push(id);
+            }
+        });
+
+    can_fall.iter().for_each(|&id| {
+        let brick = &mut bricks[id];
+        brick.z.0 -= 1;
+        brick.z.1 -= 1;
+        brick.has_fallen = true;
+        if brick.z.0 == 1 || brick.z.1 == 1 {
+            brick.falling = false;
+            event!(Level::DEBUG, "Brick {id} {:?} landet on ground", brick);
+        };
+    });
+
+    landet.iter().for_each(|&id| {
+        let brick = &mut bricks[id];
+        brick.falling = false;
+        event!(Level::DEBUG, "Brick {id} {:?} landet on other", brick);
+    });
+
+    can_fall.len()
+}
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    /// The bricks as they are in the snapshot, before they settle.
+    type Input = Vec<Brick>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Brick::try_from)
+    }
+
+    fn part1(bricks: &Self::Input, _params: &()) -> usize {
+        part1::solve(bricks)
+    }
+
+    fn part2(bricks: &Self::Input, _params: &()) -> usize {
+        part2::solve(bricks)
+    }
+}
+
+common::examples!(Day22);
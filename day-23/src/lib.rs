@@ -1,3 +1,48 @@
+use common::{ParseError, Solution};
+use grid::{Direction, Grid};
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct Day23;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trail {
+    Path,
+    Slope(Direction),
+    Forest,
+}
+
+impl TryFrom<char> for Trail {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Path),
+            '#' => Ok(Self::Forest),
+            _ => Direction::try_from(c).map(Self::Slope).map_err(|_| ()),
+        }
+    }
+}
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input = Grid<Trail>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(grid: &Self::Input, _params: &()) -> usize {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Self::Input, _params: &()) -> usize {
+        part2::solve(grid)
+    }
+}
+
+common::examples!(Day23);
@@ -1,56 +1,25 @@
-use grid::Grid;
-use pathfinding::matrix::directions::DIRECTIONS_4;
+use grid::{Direction, Grid, Point};
 use tracing::{event, instrument, Level};
 
-type Pos = (isize, isize);
+use crate::Trail;
 
-#[derive(Debug)]
-enum Trail {
-    Path,
-    Slope((isize, isize)),
-    Forest,
-}
-
-impl TryFrom<char> for Trail {
-    type Error = ();
-    fn try_from(c: char) -> Result<Self, Self::Error> {
-        match c {
-            '.' => Ok(Self::Path),
-            '^' => Ok(Self::Slope((0, -1))),
-            '>' => Ok(Self::Slope((1, 0))),
-            'v' => Ok(Self::Slope((0, 1))),
-            '<' => Ok(Self::Slope((-1, 0))),
-            '#' => Ok(Self::Forest),
-            _ => Err(()),
-        }
-    }
-}
+type Pos = Point<isize>;
 
 impl Trail {
-    fn can_go(&self, dir: Pos) -> bool {
+    fn can_go(&self, dir: Direction) -> bool {
         match self {
             Trail::Path => true,
-            Trail::Slope(slope) => (dir.0 + slope.0, dir.1 + slope.1) != (0, 0),
+            Trail::Slope(slope) => dir != slope.opposite(),
             Trail::Forest => false,
         }
     }
 }
 
-pub fn solve(input: &str) -> usize {
-    let v: Vec<(Trail, Option<usize>)> = input
-        .lines()
-        .flat_map(|line| {
-            line.chars()
-                .map(Trail::try_from)
-                .filter_map(Result::ok)
-                .map(|trail| (trail, None))
-        })
-        .collect();
-    let mut grid: Grid<(Trail, Option<usize>)> =
-        Grid::from_vec(v, input.lines().next().unwrap().len());
+pub fn solve(grid: &Grid<Trail>) -> usize {
+    let mut grid: Grid<(Trail, Option<usize>)> = grid.map(|&trail| (trail, None));
 
-    let startpos = (1, 0);
-    let target = (grid.cols() as isize - 2, grid.rows() as isize - 1);
+    let startpos = Point::new(1, 0);
+    let target = Point::new(grid.cols() as isize - 2, grid.rows() as isize - 1);
 
     let mut paths_to_target: Vec<Vec<Pos>> = Vec::new();
 
@@ -68,7 +37,6 @@ pub fn solve(input: &str) -> usize {
         }
     }
 
-    // progressbar_init(total_iterations);
     paths_to_target
         .iter()
         .max_by(|path, other| path.len().cmp(&other.len()))
@@ -83,13 +51,13 @@ fn advance(
     grid: &mut Grid<(Trail, Option<usize>)>,
     prev: Vec<Pos>,
 ) -> Vec<(Pos, Vec<Pos>)> {
-    let can_go = match grid.get(pos.1, pos.0).unwrap() {
-        (Trail::Path, _) => DIRECTIONS_4
+    let can_go = match grid.get_signed(pos.y, pos.x).unwrap() {
+        (Trail::Path, _) => Direction::ALL
             .iter()
-            .map(|dir| (dir, (pos.0 + dir.0, pos.1 + dir.1)))
+            .map(|&dir| (dir, pos.step(dir)))
             .filter(|(_, newpos)| !prev.contains(newpos))
-            .filter_map(|(&dir, newpos)| {
-                grid.get(newpos.1, newpos.0)
+            .filter_map(|(dir, newpos)| {
+                grid.get_signed(newpos.y, newpos.x)
                     .map(|trail| (dir, newpos, trail))
             })
             .filter(|&(dir, _, (trail, longest))| {
@@ -105,7 +73,7 @@ fn advance(
             })
             .map(|(_, newpos, _)| newpos)
             .collect(),
-        (Trail::Slope(slope_dir), _) => vec![(pos.0 + slope_dir.0, pos.1 + slope_dir.1)],
+        (Trail::Slope(slope_dir), _) => vec![pos.step(*slope_dir)],
         (Trail::Forest, _) => unreachable!(),
     };
     can_go
@@ -113,46 +81,8 @@ fn advance(
         .map(|&pos| {
             let mut prev = prev.clone();
             prev.push(pos);
-            grid.get_mut(pos.1, pos.0).unwrap().1 = Some(prev.len());
+            grid[(pos.y as usize, pos.x as usize)].1 = Some(prev.len());
             (pos, prev)
         })
         .collect()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::utils::tracing_init;
-    use rstest::rstest;
-
-    static EXAMPLE: &str = "#.#####################
-#.......#########...###
-#######.#########.#.###
-###.....#.>.>.###.#.###
-###v#####.#v#.###.#.###
-###.>...#.#.#.....#...#
-###v###.#.#.#########.#
-###...#.#.#.......#...#
-#####.#.#.#######.#.###
-#.....#.#.#.......#...#
-#.#####.#.#.#########v#
-#.#...#...#...###...>.#
-#.#.#v#######v###.###v#
-#...#.>.#...>.>.#.###.#
-#####v#.#.###v#.#.###.#
-#.....#...#...#.#.#...#
-#.#########.###.#.#.###
-#...###...#...#...#.###
-###.###.#.###v#####v###
-#...#...#.#.>.>.#.>.###
-#.###.###.#.###.#.#v###
-#.....###...###...#...#
-#####################.#";
-
-    #[rstest]
-    #[case(EXAMPLE, 94)]
-    fn test_example(#[case] input: &str, #[case] expected: usize) {
-        tracing_init();
-        assert_eq!(solve(input), expected);
-    }
-}
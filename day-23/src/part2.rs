@@ -1,18 +1,9 @@
-use grid::Grid;
-use pathfinding::matrix::directions::DIRECTIONS_4;
+use grid::{Direction, Grid, Point};
 use std::collections::{HashMap, HashSet};
 
-type Pos = (isize, isize);
-type Dir = (isize, isize);
+type Pos = Point<isize>;
 type Trail = bool;
 
-fn is_trail(field: char) -> Trail {
-    match field {
-        '.' | '^' | '>' | 'v' | '<' => true,
-        '#' => false,
-        _ => unreachable!(),
-    }
-}
 struct Graph {
     vertices: HashSet<Pos>,
     edges: HashMap<Pos, HashSet<(Pos, usize)>>,
@@ -20,11 +11,11 @@ struct Graph {
 
 impl Graph {
     fn find_longest_path(&self, from: Pos, to: Pos) -> usize {
-        let mut paths_to_target: Vec<Vec<&((isize, isize), usize)>> = Vec::new();
+        let mut paths_to_target: Vec<Vec<&(Pos, usize)>> = Vec::new();
 
         // let visited: HashMap<Pos, usize> = HashMap::new();
         let first = self.edges.get(&from).unwrap().iter().next().unwrap();
-        let mut paths: Vec<Vec<&((isize, isize), usize)>> = vec![vec![first]];
+        let mut paths: Vec<Vec<&(Pos, usize)>> = vec![vec![first]];
 
         while let Some(path) = paths.pop() {
             let next_edges = self.edges.get(&path.last().unwrap().0).unwrap();
@@ -35,7 +26,7 @@ impl Graph {
                     !path
                         .iter()
                         .map(|(pos, _)| pos)
-                        .collect::<Vec<&(isize, isize)>>()
+                        .collect::<Vec<&Pos>>()
                         .contains(&&next_edge.0)
                 })
                 .for_each(|next_edge| {
@@ -57,22 +48,18 @@ impl Graph {
     }
 }
 
-pub fn solve(input: &str) -> usize {
-    let v: Vec<Trail> = input
-        .lines()
-        .flat_map(|line| line.chars().map(is_trail))
-        .collect();
-    let grid: Grid<Trail> = Grid::from_vec(v, input.lines().next().unwrap().len());
+pub fn solve(grid: &Grid<crate::Trail>) -> usize {
+    let grid: Grid<Trail> = grid.map(|&trail| trail != crate::Trail::Forest);
 
-    let startpos = (1, 0);
-    let target = (grid.cols() as isize - 2, grid.rows() as isize - 1);
+    let startpos = Point::new(1, 0);
+    let target = Point::new(grid.cols() as isize - 2, grid.rows() as isize - 1);
 
     let mut g = Graph {
         vertices: HashSet::from([startpos]),
         edges: HashMap::new(),
     };
 
-    let mut queue: Vec<(Pos, Pos)> = vec![((0, 1), startpos)];
+    let mut queue: Vec<(Direction, Pos)> = vec![(Direction::Down, startpos)];
     while let Some((dir, pos)) = queue.pop() {
         if let Some((newpos, len, next_dirs)) = find_next(&grid, dir, pos, 0, target) {
             g.edges.entry(pos).or_default().insert((newpos, len));
@@ -91,25 +78,24 @@ pub fn solve(input: &str) -> usize {
 // get next node + dist when following direction, plus directions going from that node
 fn find_next(
     grid: &Grid<Trail>,
-    dir: Dir,
+    dir: Direction,
     mut pos: Pos,
     len_acc: usize,
     target: Pos,
-) -> Option<(Pos, usize, Vec<Dir>)> {
-    pos.0 += dir.0;
-    pos.1 += dir.1;
+) -> Option<(Pos, usize, Vec<Direction>)> {
+    pos = pos.step(dir);
     if pos == target {
         return Some((pos, len_acc + 1, Vec::new()));
     }
-    let next_dirs: Vec<Dir> = DIRECTIONS_4
-        .iter()
-        .filter(|next_dir| (next_dir.0 + dir.0, next_dir.1 + dir.1) != (0, 0))
-        .map(|&next_dir| (next_dir, (pos.0 + next_dir.0, pos.1 + next_dir.1)))
+    let next_dirs: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|&next_dir| next_dir != dir.opposite())
+        .map(|next_dir| (next_dir, pos.step(next_dir)))
         .filter_map(|(next_dir, next_pos)| {
-            grid.get(next_pos.1, next_pos.0)
+            grid.get_signed(next_pos.y, next_pos.x)
                 .map(|trail| (next_dir, next_pos, trail))
         })
-        .filter(|(_, _, trail)| matches!(trail, true))
+        .filter(|(_, _, &trail)| trail)
         .map(|(next_dir, _, _)| next_dir)
         .collect();
     if next_dirs.is_empty() {
@@ -120,41 +106,3 @@ fn find_next(
         Some((pos, len_acc + 1, next_dirs))
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::utils::tracing_init;
-    use rstest::rstest;
-
-    static EXAMPLE: &str = "#.#####################
-#.......#########...###
-#######.#########.#.###
-###.....#.>.>.###.#.###
-###v#####.#v#.###.#.###
-###.>...#.#.#.....#...#
-###v###.#.#.#########.#
-###...#.#.#.......#...#
-#####.#.#.#######.#.###
-#.....#.#.#.......#...#
-#.#####.#.#.#########v#
-#.#...#...#...###...>.#
-#.#.#v#######v###.###v#
-#...#.>.#...>.>.#.###.#
-#####v#.#.###v#.#.###.#
-#.....#...#...#.#.#...#
-#.#########.###.#.#.###
-#...###...#...#...#.###
-###.###.#.###v#####v###
-#...#...#.#.>.>.#.>.###
-#.###.###.#.###.#.#v###
-#.....###...###...#...#
-#####################.#";
-
-    #[rstest]
-    #[case(EXAMPLE, 154)]
-    fn test_example(#[case] input: &str, #[case] expected: usize) {
-        tracing_init();
-        assert_eq!(solve(input), expected);
-    }
-}
@@ -1,11 +1,15 @@
 extern crate day_24;
+use common::{normalize, Solution};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use day_xx::part1::solve;
+use day_24::{Day24, Params};
 
 fn criterion_benchmark(c: &mut Criterion) {
-    let input = "";
+    let input = Day24::parse(&normalize(include_str!("../res/examples/example"))).unwrap();
+    let params = Params { bounds: (7, 27) };
 
-    c.bench_function("solver part1", |b| b.iter(|| solve(black_box(input))));
+    c.bench_function("solver part1", |b| {
+        b.iter(|| Day24::part1(black_box(&input), &params))
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -1,3 +1,93 @@
+use common::{
+    parse_lines, parse_number, parse_param, split_once, ParamError, ParseError, Solution,
+};
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct Day24;
+
+#[derive(Debug, Clone)]
+pub struct Hailstone {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+    pub dx: i64,
+    pub dy: i64,
+    pub dz: i64,
+}
+
+/// One line like `19, 13, 30 @ -2,  1, -2`.
+impl TryFrom<&str> for Hailstone {
+    type Error = ParseError;
+
+    fn try_from(line: &str) -> Result<Self, Self::Error> {
+        let triple = |text: &str| -> Result<[i64; 3], ParseError> {
+            text.split(',')
+                .map(|s| parse_number(line, s.trim()))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into()
+                .map_err(|_| ParseError::new(line, text, "three numbers"))
+        };
+        let (pos, speed) = split_once(line, line, " @ ")?;
+        let [x, y, z] = triple(pos)?;
+        let [dx, dy, dz] = triple(speed)?;
+        Ok(Self {
+            x,
+            y,
+            z,
+            dx,
+            dy,
+            dz,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    /// Test area for crossing paths in part 1, `min` and `max` apply to both x
+    /// and y.
+    pub bounds: (i64, i64),
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            bounds: (200_000_000_000_000, 400_000_000_000_000),
+        }
+    }
+}
+
+impl common::Params for Params {
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParamError> {
+        match key {
+            "min" => self.bounds.0 = parse_param(key, value)?,
+            "max" => self.bounds.1 = parse_param(key, value)?,
+            _ => return Err(ParamError::Unknown(key.to_string())),
+        }
+        Ok(())
+    }
+}
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Hailstone>;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Hailstone::try_from)
+    }
+
+    fn part1(hailstones: &Self::Input, params: &Params) -> usize {
+        part1::solve(hailstones, params.bounds)
+    }
+
+    fn part2(hailstones: &Self::Input, _params: &Params) -> i64 {
+        part2::solve(hailstones)
+    }
+}
+
+common::examples!(Day24);
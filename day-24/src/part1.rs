@@ -1,60 +1,33 @@
-use indicatif::ProgressIterator;
+use common::{Progress, ProgressIterator};
 use itertools::Itertools;
 use z3::ast::{Ast, Real};
 use z3::*;
 
-use crate::utils::progressbar_init;
+use crate::Hailstone;
 
-#[derive(Debug, Clone)]
-struct Hailstone<'a> {
-    x: &'a str,
-    y: &'a str,
-    dx: &'a str,
-    dy: &'a str,
-}
-
-impl<'a> From<&'a str> for Hailstone<'a> {
-    fn from(line: &'a str) -> Self {
-        let (pos, speed) = line.split_once(" @ ").unwrap();
-        if let [x, y, _] = pos.split(", ").collect::<Vec<&str>>()[..] {
-            if let [dx, dy, _] = speed.split(", ").collect::<Vec<&str>>()[..] {
-                Self { x, y, dx, dy }
-            } else {
-                unreachable!()
-            }
-        } else {
-            unreachable!()
-        }
-    }
-}
-
-pub fn solve(input: &str, bounds: (i64, i64)) -> usize {
+pub fn solve(hailstones: &[Hailstone], bounds: (i64, i64)) -> usize {
     let cfg = Config::new();
 
-    let hailstone_combinations: Vec<_> = input
-        .lines()
-        .map(Hailstone::from)
-        .tuple_combinations()
-        .collect();
+    let hailstone_combinations: Vec<_> = hailstones.iter().tuple_combinations().collect();
 
-    let progress_bar = progressbar_init(hailstone_combinations.len() as u64);
+    let progress = Progress::new("pairs", hailstone_combinations.len() as u64);
 
     hailstone_combinations
         .iter()
-        .progress_with(progress_bar)
+        .progress(progress)
         .filter(|(hailstone, other)| {
             let ctx = Context::new(&cfg);
             let solver = Solver::new(&ctx);
 
-            let x1 = Real::from_real_str(&ctx, hailstone.x, "1").unwrap();
-            let y1 = Real::from_real_str(&ctx, hailstone.y, "1").unwrap();
-            let dx1 = Real::from_real_str(&ctx, hailstone.dx, "1").unwrap();
-            let dy1 = Real::from_real_str(&ctx, hailstone.dy, "1").unwrap();
+            let x1 = Real::from_real_str(&ctx, &hailstone.x.to_string(), "1").unwrap();
+            let y1 = Real::from_real_str(&ctx, &hailstone.y.to_string(), "1").unwrap();
+            let dx1 = Real::from_real_str(&ctx, &hailstone.dx.to_string(), "1").unwrap();
+            let dy1 = Real::from_real_str(&ctx, &hailstone.dy.to_string(), "1").unwrap();
 
-            let x2 = Real::from_real_str(&ctx, other.x, "1").unwrap();
-            let y2 = Real::from_real_str(&ctx, other.y, "1").unwrap();
-            let dx2 = Real::from_real_str(&ctx, other.dx, "1").unwrap();
-            let dy2 = Real::from_real_str(&ctx, other.dy, "1").unwrap();
+            let x2 = Real::from_real_str(&ctx, &other.x.to_string(), "1").unwrap();
+            let y2 = Real::from_real_str(&ctx, &other.y.to_string(), "1").unwrap();
+            let dx2 = Real::from_real_str(&ctx, &other.dx.to_string(), "1").unwrap();
+            let dy2 = Real::from_real_str(&ctx, &other.dy.to_string(), "1").unwrap();
 
             let lower_bound = Real::from_real_str(&ctx, &bounds.0.to_string(), "1").unwrap();
             let upper_bound = Real::from_real_str(&ctx, &bounds.1.to_string(), "1").unwrap();
@@ -78,30 +51,7 @@ pub fn solve(input: &str, bounds: (i64, i64)) -> usize {
             solver.assert(&(&y1 + &t * &dy1).le(&upper_bound));
             solver.assert(&(&y2 + &s * &dy2).le(&upper_bound));
 
-            match solver.check() {
-                z3::SatResult::Sat => true,
-                _ => false,
-            }
+            matches!(solver.check(), z3::SatResult::Sat)
         })
         .count()
 }
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-    use rstest::rstest;
-
-    static EXAMPLE: &str = "19, 13, 30 @ -2, 1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @ 1, -5, -3";
-
-    #[rstest]
-    #[case(EXAMPLE, (7, 27), 2)]
-    fn test_example(#[case] input: &str, #[case] bounds: (i64, i64), #[case] expected: usize) {
-        crate::utils::tracing_init();
-        assert_eq!(solve(input, bounds), expected);
-    }
-}
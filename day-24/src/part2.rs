@@ -2,46 +2,9 @@ use tracing::info;
 use z3::ast::{Ast, Int};
 use z3::*;
 
-struct Hailstone {
-    x: i64,
-    y: i64,
-    z: i64,
-    dx: i64,
-    dy: i64,
-    dz: i64,
-}
+use crate::Hailstone;
 
-impl From<&str> for Hailstone {
-    fn from(line: &str) -> Self {
-        let (pos, speed) = line.split_once(" @ ").unwrap();
-        if let [x, y, z] = pos
-            .split(", ")
-            .map(|s| s.parse().unwrap())
-            .collect::<Vec<i64>>()[..]
-        {
-            if let [dx, dy, dz] = speed
-                .split(", ")
-                .map(|s| s.parse().unwrap())
-                .collect::<Vec<i64>>()[..]
-            {
-                Self {
-                    x,
-                    y,
-                    z,
-                    dx,
-                    dy,
-                    dz,
-                }
-            } else {
-                unreachable!()
-            }
-        } else {
-            unreachable!()
-        }
-    }
-}
-
-pub fn solve(input: &str) -> i64 {
+pub fn solve(hailstones: &[Hailstone]) -> i64 {
     let cfg = Config::new();
     let ctx = Context::new(&cfg);
     let solver = Solver::new(&ctx);
@@ -53,8 +16,6 @@ pub fn solve(input: &str) -> i64 {
     let dy = Int::new_const(&ctx, "dy");
     let dz = Int::new_const(&ctx, "dz");
 
-    let hailstones: Vec<_> = input.lines().map(Hailstone::from).collect();
-
     hailstones.iter().enumerate().for_each(|(i, hailstone)| {
         let xi = Int::from_i64(&ctx, hailstone.x);
         let yi = Int::from_i64(&ctx, hailstone.y);
@@ -93,23 +54,3 @@ pub fn solve(input: &str) -> i64 {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-    use rstest::rstest;
-
-    static EXAMPLE: &str = "19, 13, 30 @ -2, 1, -2
-18, 19, 22 @ -1, -1, -2
-20, 25, 34 @ -2, -2, -4
-12, 31, 28 @ -1, -2, -1
-20, 19, 15 @ 1, -5, -3";
-
-    #[rstest]
-    #[case(EXAMPLE, 47)]
-    fn test_example(#[case] input: &str, #[case] expected: i64) {
-        crate::utils::tracing_init();
-        assert_eq!(solve(input), expected);
-    }
-}
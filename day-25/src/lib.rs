@@ -1,3 +1,49 @@
+use std::{collections::HashMap, convert::Infallible};
+
+use common::{split_once, ParseError, Solution};
+use rustworkx_core::petgraph::graph::UnGraph;
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const HAS_PART2: bool = false;
+
+    /// Components connected by wires, labelled with their names.
+    type Input = UnGraph<String, ()>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut g = UnGraph::new_undirected();
+        let mut node_indices = HashMap::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let (from, tos) = split_once(line, line, ": ").map_err(|err| err.offset(i))?;
+            let from_index = *node_indices
+                .entry(from)
+                .or_insert_with(|| g.add_node(from.to_string()));
+            tos.split(' ').for_each(|to| {
+                let to_index = *node_indices
+                    .entry(to)
+                    .or_insert_with(|| g.add_node(to.to_string()));
+                g.add_edge(from_index, to_index, ());
+            });
+        }
+        Ok(g)
+    }
+
+    fn part1(graph: &Self::Input, _params: &()) -> usize {
+        part1::solve(graph)
+    }
+
+    fn part2(_graph: &Self::Input, _params: &()) -> Infallible {
+        unreachable!("day 25 has no part 2")
+    }
+}
+
+common::examples!(Day25);
@@ -0,0 +1,289 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+/// A heading on the map, `Up` is towards row 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise starting upwards.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// One step in this direction, `y` grows downwards.
+    pub fn delta(self) -> Point<isize> {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+/// Accepts the spellings found in the inputs: `U`/`R`/`D`/`L` and
+/// `^`/`>`/`v`/`<`.
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | '^' => Ok(Direction::Up),
+            'R' | '>' => Ok(Direction::Right),
+            'D' | 'v' => Ok(Direction::Down),
+            'L' | '<' => Ok(Direction::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+/// Accepts `up`, `right`, `down` and `left` as well as the single characters
+/// of [`Direction::try_from`].
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "up" => Ok(Direction::Up),
+            "right" => Ok(Direction::Right),
+            "down" => Ok(Direction::Down),
+            "left" => Ok(Direction::Left),
+            _ => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Direction::try_from(c).map_err(|_| s.to_string()),
+                    _ => Err(s.to_string()),
+                }
+            }
+        }
+    }
+}
+
+/// A position or offset, `x` is the column and `y` the row.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Add<Output = T>> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        let dx = self.x.max(other.x) - self.x.min(other.x);
+        let dy = self.y.max(other.y) - self.y.min(other.y);
+        dx + dy
+    }
+}
+
+impl Point<isize> {
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+
+    pub fn step_by(self, direction: Direction, n: isize) -> Self {
+        self + direction.delta() * n
+    }
+}
+
+impl Point<usize> {
+    /// The neighbour in `direction`, if it is still inside a map of `width`
+    /// columns and `height` rows.
+    pub fn checked_step(self, direction: Direction, width: usize, height: usize) -> Option<Self> {
+        let delta = direction.delta();
+        let x = self.x.checked_add_signed(delta.x)?;
+        let y = self.y.checked_add_signed(delta.y)?;
+        (x < width && y < height).then_some(Point { x, y })
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Twice the area enclosed by a simple polygon (shoelace formula). The
+/// polygon is closed implicitly, the first vertex may or may not be repeated
+/// at the end. Doubled so it stays exact for lattice polygons.
+pub fn double_area(vertices: &[Point<isize>]) -> isize {
+    edges(vertices)
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum::<isize>()
+        .abs()
+}
+
+/// Number of lattice points on the outline of the polygon.
+pub fn boundary_points(vertices: &[Point<isize>]) -> isize {
+    edges(vertices)
+        .map(|(a, b)| gcd((b.x - a.x).abs(), (b.y - a.y).abs()))
+        .sum()
+}
+
+/// Number of lattice points strictly inside the polygon (Pick's theorem).
+pub fn interior_points(vertices: &[Point<isize>]) -> isize {
+    (double_area(vertices) - boundary_points(vertices) + 2) / 2
+}
+
+fn edges(vertices: &[Point<isize>]) -> impl Iterator<Item = (Point<isize>, Point<isize>)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+fn gcd(a: isize, b: isize) -> isize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Direction::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.delta() + dir.opposite().delta(), Point::new(0, 0));
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("down".parse(), Ok(Direction::Down));
+        assert_eq!("L".parse(), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+        assert!("north".parse::<Direction>().is_err());
+    }
+
+    #[test]
+    fn points() {
+        let p = Point::new(2isize, 3);
+        assert_eq!(p.step(Direction::Up), Point::new(2, 2));
+        assert_eq!(p.step_by(Direction::Left, 3), Point::new(-1, 3));
+        assert_eq!(p.manhattan(Point::new(-1, 5)), 5);
+        assert_eq!(p - Point::new(1, 1), Point::new(1, 2));
+    }
+
+    #[test]
+    fn checked_step() {
+        let p = Point::new(0usize, 1);
+        assert_eq!(p.checked_step(Direction::Left, 2, 2), None);
+        assert_eq!(p.checked_step(Direction::Down, 2, 2), None);
+        assert_eq!(p.checked_step(Direction::Up, 2, 2), Some(Point::new(0, 0)));
+        assert_eq!(p.manhattan(Point::new(3, 0)), 4);
+    }
+
+    #[test]
+    fn pick() {
+        // 3x3 square, 12 points on the outline and 4 inside.
+        let square = [
+            Point::new(0, 0),
+            Point::new(3, 0),
+            Point::new(3, 3),
+            Point::new(0, 3),
+        ];
+        assert_eq!(double_area(&square), 18);
+        assert_eq!(boundary_points(&square), 12);
+        assert_eq!(interior_points(&square), 4);
+
+        let triangle = [Point::new(0, 0), Point::new(4, 0), Point::new(0, 4)];
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), 3);
+    }
+}
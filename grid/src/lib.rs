@@ -0,0 +1,333 @@
+pub mod geometry;
+mod parse;
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+pub use geometry::{Direction, Point};
+pub use parse::ParseError;
+
+/// A rectangular map stored row by row.
+///
+/// Positions are `(row, col)` with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+/// Offsets of the four direct neighbours, clockwise starting upwards.
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of all eight surrounding tiles, clockwise starting upwards.
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order.
+    ///
+    /// Panics if the cells don't fill complete rows.
+    pub fn from_vec(cells: Vec<T>, cols: usize) -> Self {
+        assert!(
+            cols > 0 && cells.len().is_multiple_of(cols),
+            "{} cells do not fill rows of {cols}",
+            cells.len()
+        );
+        Grid {
+            rows: cells.len() / cols,
+            cells,
+            cols,
+        }
+    }
+
+    pub fn init(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// `(rows, cols)`
+    pub fn size(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        (row < self.rows && col < self.cols).then(|| &self.cells[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        (row < self.rows && col < self.cols).then(|| &mut self.cells[row * self.cols + col])
+    }
+
+    /// Like [`Grid::get`], for positions that may have left the grid on the
+    /// top or left side.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        self.get(row.try_into().ok()?, col.try_into().ok()?)
+    }
+
+    /// Treats the grid as infinitely tiled in all directions.
+    pub fn get_wrapping(&self, row: isize, col: isize) -> &T {
+        let row = row.rem_euclid(self.rows as isize) as usize;
+        let col = col.rem_euclid(self.cols as isize) as usize;
+        &self.cells[row * self.cols + col]
+    }
+
+    /// The neighbour of `from` in `direction`, unless that leaves the grid.
+    pub fn step(&self, from: Point<usize>, direction: Direction) -> Option<Point<usize>> {
+        from.checked_step(direction, self.cols, self.rows)
+    }
+
+    /// Position of the first tile in reading order matching `predicate`.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.cells.iter().position(predicate)?;
+        Some((i / self.cols, i % self.cols))
+    }
+
+    /// All tiles in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// All tiles in row-major order together with their position.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn iter_rows(&self) -> std::slice::Chunks<'_, T> {
+        self.cells.chunks(self.cols)
+    }
+
+    pub fn iter_col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(col < self.cols, "column {col} out of bounds");
+        self.cells[col..].iter().step_by(self.cols)
+    }
+
+    /// The up to four tiles sharing an edge with `(row, col)` that lie inside
+    /// the grid.
+    pub fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &NEIGHBOURS_4)
+    }
+
+    /// The up to eight tiles sharing an edge or a corner with `(row, col)`
+    /// that lie inside the grid.
+    pub fn neighbours8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr)?;
+            let col = col.checked_add_signed(dc)?;
+            (row < self.rows && col < self.cols).then_some((row, col))
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirrors the grid along its main diagonal, rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            cells: (0..self.cols)
+                .flat_map(|col| self.iter_col(col).cloned())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Rotates by 90° clockwise, the first column becomes the first row.
+    pub fn rotate_cw(&self) -> Self {
+        Grid {
+            cells: (0..self.cols)
+                .flat_map(|col| self.iter_col(col).rev().cloned())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Rotates by 90° counter-clockwise, the last column becomes the first
+    /// row.
+    pub fn rotate_ccw(&self) -> Self {
+        Grid {
+            cells: (0..self.cols)
+                .rev()
+                .flat_map(|col| self.iter_col(col).cloned())
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("({row}, {col}) is outside of the grid"))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point<usize>) -> &T {
+        &self[(point.y, point.x)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, point: Point<usize>) -> &mut T {
+        &mut self[(point.y, point.x)]
+    }
+}
+
+/// Prints one line per row, the way the map appears in the puzzle input.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn access() {
+        let grid = grid();
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid.get(1, 0), Some(&'d'));
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid[(0, 2)], 'c');
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(
+            grid.step(Point::new(2, 0), Direction::Down),
+            Some(Point::new(2, 1))
+        );
+        assert_eq!(grid.step(Point::new(2, 0), Direction::Right), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = grid();
+        assert_eq!(grid.get_wrapping(0, 3), &'a');
+        assert_eq!(grid.get_wrapping(-1, -1), &'f');
+        assert_eq!(grid.get_wrapping(4, -4), &'c');
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours(1, 1).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours8(0, 1).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 2), (1, 1), (1, 0), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.iter_col(2).collect::<String>(), "cf");
+        assert_eq!(grid.iter_rows().count(), 2);
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn display() {
+        let example = "#.#\n...";
+        assert_eq!(Grid::<char>::parse(example).unwrap().to_string(), example);
+    }
+}
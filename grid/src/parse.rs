@@ -0,0 +1,167 @@
+use std::{convert::Infallible, fmt};
+
+use crate::Grid;
+
+/// Why a map could not be read. Rows and columns are counted from 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError<E = Infallible> {
+    Empty,
+    /// A row is longer or shorter than the first one.
+    Ragged {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// A character could not be turned into a tile.
+    Tile {
+        row: usize,
+        col: usize,
+        ch: char,
+        error: E,
+    },
+}
+
+impl<T> Grid<T> {
+    /// Reads a map with one line per row, converting every character into a
+    /// tile.
+    pub fn parse(input: &str) -> Result<Self, ParseError<T::Error>>
+    where
+        T: TryFrom<char>,
+    {
+        Self::parse_with(input, T::try_from)
+    }
+
+    /// Like [`Grid::parse`], with the tile conversion given explicitly, e.g.
+    /// for digits.
+    pub fn parse_with<E>(
+        input: &str,
+        mut tile: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError<E>> {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut cols = None;
+
+        for (row, line) in input.lines().enumerate() {
+            let len = cells.len();
+            for (col, ch) in line.chars().enumerate() {
+                cells.push(tile(ch).map_err(|error| ParseError::Tile {
+                    row,
+                    col,
+                    ch,
+                    error,
+                })?);
+            }
+            let len = cells.len() - len;
+            match cols {
+                None => cols = Some(len),
+                Some(expected) if expected != len => {
+                    return Err(ParseError::Ragged { row, len, expected })
+                }
+                Some(_) => (),
+            }
+        }
+
+        match cols {
+            Some(cols) if cols > 0 => Ok(Grid::from_vec(cells, cols)),
+            _ => Err(ParseError::Empty),
+        }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "the map is empty"),
+            ParseError::Ragged { row, len, expected } => {
+                write!(f, "row {row} has {len} tiles instead of {expected}")
+            }
+            ParseError::Tile {
+                row,
+                col,
+                ch,
+                error,
+            } => {
+                write!(
+                    f,
+                    "invalid tile `{ch}` in row {row}, column {col}: {error:?}"
+                )
+            }
+        }
+    }
+}
+
+impl<E: fmt::Debug> std::error::Error for ParseError<E> {}
+
+/// Locates the problem for the runner, rows become lines.
+impl<E> From<ParseError<E>> for common::ParseError {
+    fn from(err: ParseError<E>) -> Self {
+        match err {
+            ParseError::Empty => common::ParseError::missing("a map"),
+            ParseError::Ragged { row, len, expected } => common::ParseError {
+                day: 0,
+                line: row + 1,
+                column: expected.min(len) + 1,
+                token: String::new(),
+                expected: format!("{expected} tiles in every row, found {len}"),
+            },
+            ParseError::Tile { row, col, ch, .. } => common::ParseError {
+                day: 0,
+                line: row + 1,
+                column: col + 1,
+                token: ch.to_string(),
+                expected: "a map tile".to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits() {
+        let grid = Grid::parse_with("12\n34", |c| c.to_digit(10).ok_or(())).unwrap();
+        assert_eq!(grid.iter().sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn invalid_tile() {
+        assert_eq!(
+            Grid::parse_with("12\n3x", |c| c.to_digit(10).ok_or(())),
+            Err(ParseError::Tile {
+                row: 1,
+                col: 1,
+                ch: 'x',
+                error: ()
+            })
+        );
+    }
+
+    #[test]
+    fn ragged() {
+        assert_eq!(
+            Grid::<char>::parse("..\n...\n.."),
+            Err(ParseError::Ragged {
+                row: 1,
+                len: 3,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn located() {
+        let err = common::ParseError::from(Grid::<char>::parse("..\n...").unwrap_err());
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err: common::ParseError = Grid::parse_with("12\n3x", |c| c.to_digit(10).ok_or(()))
+            .unwrap_err()
+            .into();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(Grid::<char>::parse(""), Err(ParseError::Empty));
+    }
+}
@@ -1,3 +1,30 @@
+use common::{ParseError, Solution};
+
 pub mod part1;
 pub mod part2;
-pub mod utils;
+
+pub struct DayXX;
+
+impl Solution for DayXX {
+    const DAY: u8 = 0;
+
+    /// The lines of the puzzle input.
+    type Input = Vec<String>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Input, _params: &()) -> usize {
+        part1::solve(lines)
+    }
+
+    fn part2(lines: &Self::Input, _params: &()) -> usize {
+        part2::solve(lines)
+    }
+}
+
+common::examples!(DayXX);
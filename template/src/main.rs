@@ -1,11 +0,0 @@
-use day_xx::{part1::solve, utils::tracing_init};
-use std::fs::read_to_string;
-use tracing::{event, Level};
-
-fn main() {
-    tracing_init();
-    let input = read_to_string("res/input").unwrap();
-
-    let result = solve(&input, 0);
-    event!(Level::INFO, "{}", result);
-}
@@ -0,0 +1,3 @@
+pub fn solve(_lines: &[String]) -> usize {
+    0
+}